@@ -1,3 +1,5 @@
+#![deny(unsafe_op_in_unsafe_fn)]
+
 /// # Pointer-Based Doubly-Linked List
 ///
 /// This library provides an implementation of a doubly-linked list in Rust using raw pointers.
@@ -25,7 +27,15 @@
 ///     }
 /// }
 /// ```
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::iter::FusedIterator;
+use std::mem::MaybeUninit;
+use std::ops::{Add, AddAssign, Bound, Index, IndexMut, RangeBounds};
 use std::ptr;
+use std::str::FromStr;
 
 /// Represents a node in the doubly-linked list.
 struct Node<T> {
@@ -45,6 +55,58 @@ impl<T> Node<T> {
     }
 }
 
+/// The in-progress state of [`DoublyLinkedList::sort_by`].
+///
+/// While a pass runs, the nodes are split between the chain merged so far (`head` to
+/// `tail`), the `p_size` nodes of the left run still to merge starting at `p`, and the
+/// null-terminated chain starting at `q`. Dropping the guard joins the three back
+/// together and repairs the list's `prev` pointers, `tail` and `len`, so the elements
+/// survive even if the comparator panics.
+struct MergeGuard<'a, T> {
+    list: &'a mut DoublyLinkedList<T>,
+    head: *mut Node<T>,
+    tail: *mut Node<T>,
+    p: *mut Node<T>,
+    p_size: usize,
+    q: *mut Node<T>,
+}
+
+impl<T> Drop for MergeGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the three chains described above are disjoint and together hold every node
+        // of the list, so joining and walking them only touches live nodes.
+        unsafe {
+            let mut rest = self.q;
+            if self.p_size > 0 {
+                let mut last = self.p;
+                for _ in 1..self.p_size {
+                    last = (*last).next;
+                }
+                (*last).next = self.q;
+                rest = self.p;
+            }
+            if self.tail.is_null() {
+                self.head = rest;
+            } else {
+                (*self.tail).next = rest;
+            }
+
+            let mut prev = ptr::null_mut();
+            let mut current = self.head;
+            let mut len = 0;
+            while !current.is_null() {
+                (*current).prev = prev;
+                prev = current;
+                current = (*current).next;
+                len += 1;
+            }
+            self.list.head = self.head;
+            self.list.tail = prev;
+            self.list.len = len;
+        }
+    }
+}
+
 /// A pointer-based doubly-linked list with explicit memory management.
 pub struct DoublyLinkedList<T> {
     head: *mut Node<T>,
@@ -62,9 +124,37 @@ impl<T> DoublyLinkedList<T> {
         }
     }
 
+    /// Creates a list containing a clone of every element of the slice, in order.
+    pub fn from_slice(s: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        s.iter().cloned().collect()
+    }
+
+    /// Creates a list containing `n` copies of `value`, like `vec![value; n]`.
+    ///
+    /// `value` is cloned `n - 1` times and moved into the last node.
+    pub fn repeat(value: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut list = Self::new();
+        list.resize(n, value);
+        list
+    }
+
+    /// Creates a list of `n` elements where each element is `f(index)`.
+    ///
+    /// `f` is called once per index, in order from `0` to `n - 1`.
+    pub fn from_fn(n: usize, f: impl FnMut(usize) -> T) -> Self {
+        (0..n).map(f).collect()
+    }
+
     /// Appends a value to the end of the list.
     pub fn append(&mut self, value: T) {
         let new_node = Node::new(value);
+        // SAFETY: `new_node` was just allocated, and `tail` is either null or a live node.
         unsafe {
             if !self.tail.is_null() {
                 (*new_node).prev = self.tail;
@@ -80,6 +170,7 @@ impl<T> DoublyLinkedList<T> {
     /// Prepends a value to the beginning of the list.
     pub fn prepend(&mut self, value: T) {
         let new_node = Node::new(value);
+        // SAFETY: `new_node` was just allocated, and `head` is either null or a live node.
         unsafe {
             if !self.head.is_null() {
                 (*new_node).next = self.head;
@@ -92,6 +183,185 @@ impl<T> DoublyLinkedList<T> {
         self.len += 1;
     }
 
+    /// Moves every element of `other` to the end of this list in O(1) time.
+    ///
+    /// `other` is left empty.
+    pub fn append_list(&mut self, other: &mut Self) {
+        if other.head.is_null() {
+            return;
+        }
+        // SAFETY: `other.head` was checked to be non-null, and `self.tail` is null or a live node.
+        unsafe {
+            if self.tail.is_null() {
+                self.head = other.head;
+            } else {
+                (*self.tail).next = other.head;
+                (*other.head).prev = self.tail;
+            }
+        }
+        self.tail = other.tail;
+        self.len += other.len;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+
+    /// Moves every element of `other` to the front of this list in O(1) time.
+    ///
+    /// `other` is left empty.
+    pub fn prepend_list(&mut self, other: &mut Self) {
+        if other.tail.is_null() {
+            return;
+        }
+        // SAFETY: `other.tail` was checked to be non-null, and `self.head` is null or a live node.
+        unsafe {
+            if self.head.is_null() {
+                self.tail = other.tail;
+            } else {
+                (*other.tail).next = self.head;
+                (*self.head).prev = other.tail;
+            }
+        }
+        self.head = other.head;
+        self.len += other.len;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+
+    /// Removes the first element from the list and returns it.
+    ///
+    /// Returns `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() {
+            return None;
+        }
+        // SAFETY: `head` is non-null, so it was allocated by `Node::new` and is owned by this list.
+        // It is fully unlinked before its value is handed out.
+        unsafe {
+            let node = Box::from_raw(self.head);
+            self.head = node.next;
+            if self.head.is_null() {
+                self.tail = ptr::null_mut();
+            } else {
+                (*self.head).prev = ptr::null_mut();
+            }
+            self.len -= 1;
+            Some(node.value)
+        }
+    }
+
+    /// Removes the last element from the list and returns it.
+    ///
+    /// Returns `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.tail.is_null() {
+            return None;
+        }
+        // SAFETY: `tail` is non-null, so it was allocated by `Node::new` and is owned by this list.
+        // It is fully unlinked before its value is handed out.
+        unsafe {
+            let node = Box::from_raw(self.tail);
+            self.tail = node.prev;
+            if self.tail.is_null() {
+                self.head = ptr::null_mut();
+            } else {
+                (*self.tail).next = ptr::null_mut();
+            }
+            self.len -= 1;
+            Some(node.value)
+        }
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    pub fn peek_front(&self) -> Option<&T> {
+        // SAFETY: `head` is null or a live node, and the returned reference borrows `self`.
+        unsafe { self.head.as_ref().map(|node| &node.value) }
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    pub fn peek_back(&self) -> Option<&T> {
+        // SAFETY: `tail` is null or a live node, and the returned reference borrows `self`.
+        unsafe { self.tail.as_ref().map(|node| &node.value) }
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the list is empty.
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `head` is null or a live node, and the returned reference borrows `self` mutably.
+        unsafe { self.head.as_mut().map(|node| &mut node.value) }
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if the list is empty.
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `tail` is null or a live node, and the returned reference borrows `self` mutably.
+        unsafe { self.tail.as_mut().map(|node| &mut node.value) }
+    }
+
+    /// Returns a reference to the first element, or `None` if the list is empty.
+    ///
+    /// This is the slice-style name for [`peek_front`](Self::peek_front); both do the same thing.
+    pub fn first(&self) -> Option<&T> {
+        self.peek_front()
+    }
+
+    /// Returns a reference to the last element, or `None` if the list is empty.
+    ///
+    /// This is the slice-style name for [`peek_back`](Self::peek_back); both do the same thing.
+    pub fn last(&self) -> Option<&T> {
+        self.peek_back()
+    }
+
+    /// Returns a mutable reference to the first element, or `None` if the list is empty.
+    ///
+    /// This is an alias for [`peek_front_mut`](Self::peek_front_mut).
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.peek_front_mut()
+    }
+
+    /// Returns a mutable reference to the last element, or `None` if the list is empty.
+    ///
+    /// This is an alias for [`peek_back_mut`](Self::peek_back_mut).
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.peek_back_mut()
+    }
+
+    /// Prepends every item of the iterator so that the first item ends up at the front.
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut other: Self = iter.into_iter().collect();
+        self.prepend_list(&mut other);
+    }
+
+    /// Appends every item of the iterator to the back, in iteration order.
+    ///
+    /// This is equivalent to [`Extend::extend`].
+    pub fn append_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend(iter);
+    }
+
+    /// Appends a clone of every element of `slice` to the back, in order.
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        for value in slice {
+            self.append(value.clone());
+        }
+    }
+
+    /// Prepends every item of the iterator so that the first item ends up at the head.
+    ///
+    /// This is equivalent to [`extend_front`](Self::extend_front).
+    pub fn prepend_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.extend_front(iter);
+    }
+
+    /// Removes and frees every element, leaving the list empty.
+    pub fn clear(&mut self) {
+        // Popping keeps the list consistent after every step, so a panicking destructor
+        // cannot leave `len` or the end pointers out of date.
+        while self.pop_front().is_some() {}
+    }
+
     /// Returns the number of elements in the list.
     pub fn len(&self) -> usize {
         self.len
@@ -101,101 +371,5061 @@ impl<T> DoublyLinkedList<T> {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
-}
 
-/// Ensures all nodes in the list are properly deallocated when the list goes out of scope.
-impl<T> Drop for DoublyLinkedList<T> {
-    fn drop(&mut self) {
-        while !self.head.is_null() {
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// This walks the list from whichever end is closer, so it runs in O(n) time.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: `index < len` was checked above, so `node_at` returns a live node.
+        unsafe { Some(&(*self.node_at(index)).value) }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// This walks the list from whichever end is closer, so it runs in O(n) time.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: `index < len` was checked above, so `node_at` returns a live node.
+        unsafe { Some(&mut (*self.node_at(index)).value) }
+    }
+
+    /// Returns a reference to the element at `index` without checking that it is in bounds.
+    ///
+    /// This still walks the list from whichever end is closer; it only skips the
+    /// bounds check that [`get`](Self::get) performs.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than [`len`](Self::len). Calling this with an
+    /// out-of-bounds index is undefined behavior, even if the returned reference
+    /// is never used.
+    pub unsafe fn get_unchecked(&self, index: usize) -> &T {
+        // SAFETY: the caller guarantees `index < len`, so `node_at` returns a live node.
+        unsafe { &(*self.node_at(index)).value }
+    }
+
+    /// Returns a mutable reference to the element at `index` without checking that it is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than [`len`](Self::len), as for
+    /// [`get_unchecked`](Self::get_unchecked).
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        // SAFETY: the caller guarantees `index < len`, so `node_at` returns a live node.
+        unsafe { &mut (*self.node_at(index)).value }
+    }
+
+    /// Inserts a value at `index`, shifting every element after it towards the tail.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert_at(&mut self, index: usize, value: T) {
+        assert!(
+            index <= self.len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            self.len
+        );
+        if index == 0 {
+            return self.prepend(value);
+        }
+        if index == self.len {
+            return self.append(value);
+        }
+        let new_node = Node::new(value);
+        // SAFETY: `0 < index < len`, so the node at `index - 1` and its successor are both live.
+        unsafe {
+            let prev = self.node_at(index - 1);
+            let next = (*prev).next;
+            (*new_node).prev = prev;
+            (*new_node).next = next;
+            (*prev).next = new_node;
+            (*next).prev = new_node;
+        }
+        self.len += 1;
+    }
+
+    /// Removes the element at `index` and returns it, or `None` if it is out of bounds.
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let node = self.node_at(index);
+        Some(self.unlink(node))
+    }
+
+    /// Returns `true` if the list contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|x| x == value)
+    }
+
+    /// Returns the index of the first element matching `pred`, searching from the head.
+    pub fn position<F>(&self, pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred)
+    }
+
+    /// Returns the index of the last element matching `pred`, searching from the tail.
+    pub fn rposition<F>(&self, pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().rposition(pred)
+    }
+
+    /// Returns a reference to the first element matching `pred`, searching from the head.
+    pub fn find<F>(&self, mut pred: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().find(|x| pred(x))
+    }
+
+    /// Returns a mutable reference to the first element matching `pred`, searching from the head.
+    pub fn find_mut<F>(&mut self, mut pred: F) -> Option<&mut T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_mut().find(|x| pred(x))
+    }
+
+    /// Reverses the order of the elements in place without allocating.
+    pub fn reverse(&mut self) {
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is null-checked by the loop, and every node reachable from `head`
+            // is live.
             unsafe {
-                let node = Box::from_raw(self.head);
-                self.head = node.next;
+                let node = &mut *current;
+                std::mem::swap(&mut node.prev, &mut node.next);
+                current = node.prev;
             }
         }
+        std::mem::swap(&mut self.head, &mut self.tail);
     }
-}
 
-/// Iterator for the doubly-linked list.
-pub struct Iter<'a, T> {
-    current: *mut Node<T>,
-    _marker: std::marker::PhantomData<&'a T>,
-}
+    /// Sorts the list in ascending order.
+    ///
+    /// See [`sort_by`](Self::sort_by) for details.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Sorts the list with a comparator function.
+    ///
+    /// This is a stable, bottom-up merge sort that runs in O(n log n) time. Nodes are
+    /// re-linked in place, so no elements are moved and no memory is allocated.
+    ///
+    /// If `cmp` panics, every element is kept in the list, but in an unspecified order.
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self.len < 2 {
+            return;
+        }
+        // The first pass merges the whole list as a single unmerged chain.
+        let mut merge = MergeGuard {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            p: ptr::null_mut(),
+            p_size: 0,
+            q: self.head,
+            list: self,
+        };
 
-    fn next(&mut self) -> Option<Self::Item> {
+        let mut width = 1;
+        // SAFETY: every node of the list is reachable from exactly one of the merged chain, the
+        // `p_size` nodes from `p` or the null-terminated chain from `q`. Pointers are only
+        // followed while those counts or null checks say a node is there.
         unsafe {
-            if self.current.is_null() {
-                None
-            } else {
-                let node = &*self.current;
-                self.current = node.next;
-                Some(&node.value)
+            loop {
+                let mut merges = 0;
+
+                while !merge.q.is_null() {
+                    merges += 1;
+                    merge.p = merge.q;
+                    merge.p_size = 0;
+                    while merge.p_size < width && !merge.q.is_null() {
+                        merge.p_size += 1;
+                        merge.q = (*merge.q).next;
+                    }
+                    let mut q_size = width;
+
+                    while merge.p_size > 0 || (q_size > 0 && !merge.q.is_null()) {
+                        let take_q = if merge.p_size == 0 {
+                            true
+                        } else if q_size == 0 || merge.q.is_null() {
+                            false
+                        } else {
+                            cmp(&(*merge.p).value, &(*merge.q).value) == Ordering::Greater
+                        };
+                        let node = if take_q {
+                            let node = merge.q;
+                            merge.q = (*node).next;
+                            q_size -= 1;
+                            node
+                        } else {
+                            let node = merge.p;
+                            merge.p = (*node).next;
+                            merge.p_size -= 1;
+                            node
+                        };
+                        if merge.tail.is_null() {
+                            merge.head = node;
+                        } else {
+                            (*merge.tail).next = node;
+                        }
+                        merge.tail = node;
+                    }
+                }
+                (*merge.tail).next = ptr::null_mut();
+
+                // Dropping `merge` links the sorted chain back into the list.
+                if merges <= 1 {
+                    return;
+                }
+                merge.q = merge.head;
+                merge.head = ptr::null_mut();
+                merge.tail = ptr::null_mut();
+                width *= 2;
             }
         }
     }
-}
 
-/// Allows the list to be iterated over.
-impl<T> DoublyLinkedList<T> {
-    pub fn iter(&self) -> Iter<T> {
-        Iter {
-            current: self.head,
+    /// Sorts the list with a key extraction function.
+    ///
+    /// The key function is called twice per comparison. See [`sort_by`](Self::sort_by) for
+    /// details.
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that map to the same key, keeping the first of each run.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements that satisfy the given equality relation.
+    ///
+    /// `same_bucket` is passed the current element and the last retained element before it,
+    /// in that order. If it returns `true`, the current element is removed.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        if self.head.is_null() {
+            return;
+        }
+        // SAFETY: `prev` and `current` are distinct live nodes, and `next` is read before `current`
+        // may be unlinked.
+        unsafe {
+            let mut prev = self.head;
+            let mut current = (*prev).next;
+            while !current.is_null() {
+                let next = (*current).next;
+                if same_bucket(&mut (*current).value, &mut (*prev).value) {
+                    self.unlink(current);
+                } else {
+                    prev = current;
+                }
+                current = next;
+            }
+        }
+    }
+
+    /// Retains only the elements for which `pred` returns `true`, preserving their order.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| pred(x));
+    }
+
+    /// Retains only the elements for which `pred` returns `true`, passing each element
+    /// mutably so it can be updated in the same pass.
+    pub fn retain_mut<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut current = self.head;
+        while !current.is_null() {
+            // SAFETY: `current` is live, and `next` is read before `current` may be unlinked.
+            unsafe {
+                let next = (*current).next;
+                if !pred(&mut (*current).value) {
+                    self.unlink(current);
+                }
+                current = next;
+            }
+        }
+    }
+
+    /// Removes the elements in `range` from the list and returns them as an iterator.
+    ///
+    /// The range is unlinked as soon as `drain` is called and the elements on either side
+    /// are joined back together, so the list stays valid even if the iterator is leaked.
+    /// Elements that are not consumed are dropped along with the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the end is greater
+    /// than the length of the list.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let (start, end) = self.resolve_range(range);
+        Drain {
+            inner: self.detach_range(start, end),
             _marker: std::marker::PhantomData,
         }
     }
-}
 
-impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
-    type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
+    /// Replaces the elements in `range` with the items of `replace_with`.
+    ///
+    /// The removed elements are dropped; use [`drain`](Self::drain) to keep them.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`drain`](Self::drain).
+    pub fn splice_range<R, I>(&mut self, range: R, replace_with: I)
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = self.resolve_range(range);
+        let mut replacement: Self = replace_with.into_iter().collect();
+        drop(self.detach_range(start, end));
+        let prev = if start == 0 {
+            ptr::null_mut()
+        } else {
+            self.node_at(start - 1)
+        };
+        let next = if prev.is_null() {
+            self.head
+        } else {
+            // SAFETY: `prev` is non-null here, so it is the live node returned by `node_at`.
+            unsafe { (*prev).next }
+        };
+        self.splice_between(prev, next, &mut replacement);
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Splits the list in two at `at`, returning everything from index `at` onwards.
+    ///
+    /// The split point is found by walking from whichever end is closer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.len,
+            "`at` split index (is {}) should be <= len (is {})",
+            at,
+            self.len
+        );
+        self.detach_range(at, self.len)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Rotates the list so that the first `n` elements move to the back.
+    ///
+    /// `n` is reduced modulo the length of the list. Only the new head has to be located, so
+    /// this walks at most half of the list and never allocates.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let n = n % self.len;
+        if n == 0 {
+            return;
+        }
+        // SAFETY: `0 < n < len`, so `new_head`, its predecessor, `head` and `tail` are all live.
+        unsafe {
+            let new_head = self.node_at(n);
+            let new_tail = (*new_head).prev;
+            (*self.tail).next = self.head;
+            (*self.head).prev = self.tail;
+            (*new_tail).next = ptr::null_mut();
+            (*new_head).prev = ptr::null_mut();
+            self.head = new_head;
+            self.tail = new_tail;
+        }
+    }
 
-    #[test]
-    fn test_append() {
-        let mut list = DoublyLinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
-        assert_eq!(list.len(), 3);
+    /// Rotates the list so that the last `n` elements move to the front.
+    ///
+    /// `n` is reduced modulo the length of the list.
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+        let n = n % self.len;
+        if n != 0 {
+            self.rotate_left(self.len - n);
+        }
     }
 
-    #[test]
-    fn test_prepend() {
-        let mut list = DoublyLinkedList::new();
-        list.prepend(3);
-        list.prepend(2);
-        list.prepend(1);
-        assert_eq!(list.len(), 3);
+    /// Swaps the elements at indices `i` and `j` by exchanging their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        for index in [i, j] {
+            assert!(
+                index < self.len,
+                "index out of bounds: the len is {} but the index is {}",
+                self.len,
+                index
+            );
+        }
+        if i == j {
+            return;
+        }
+        // SAFETY: both indices are in bounds and distinct, so `a` and `b` are different live nodes
+        // and the two mutable references do not overlap.
+        unsafe {
+            let a = self.node_at(i);
+            let b = self.node_at(j);
+            ptr::swap(&mut (*a).value, &mut (*b).value);
+        }
     }
 
-    #[test]
-    fn test_empty_list() {
-        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
-        assert!(list.is_empty());
-        assert_eq!(list.len(), 0);
+    /// Swaps the node at index `i` with the node after it by re-linking them.
+    ///
+    /// Unlike [`swap`](Self::swap), no values are moved, which avoids copying large elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i + 1 >= len`.
+    pub fn swap_adjacent(&mut self, i: usize) {
+        assert!(
+            i < self.len.saturating_sub(1),
+            "adjacent swap index (is {}) should be < len - 1 (len is {})",
+            i,
+            self.len
+        );
+        // SAFETY: `i + 1 < len`, so `a` and its successor `b` are live. `before` and `after` are
+        // null-checked.
+        unsafe {
+            let a = self.node_at(i);
+            let b = (*a).next;
+            let before = (*a).prev;
+            let after = (*b).next;
+            if before.is_null() {
+                self.head = b;
+            } else {
+                (*before).next = b;
+            }
+            if after.is_null() {
+                self.tail = a;
+            } else {
+                (*after).prev = a;
+            }
+            (*b).prev = before;
+            (*b).next = a;
+            (*a).prev = b;
+            (*a).next = after;
+        }
     }
 
-    #[test]
-    fn test_iteration() {
-        let mut list = DoublyLinkedList::new();
-        list.append(1);
-        list.append(2);
-        list.append(3);
+    /// Shortens the list to its first `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        drop(self.split_off(len));
+    }
 
-        let mut iter = list.iter();
-        assert_eq!(iter.next(), Some(&1));
-        assert_eq!(iter.next(), Some(&2));
-        assert_eq!(iter.next(), Some(&3));
-        assert_eq!(iter.next(), None);
+    /// Resizes the list to `new_len` elements.
+    ///
+    /// If the list grows, clones of `value` are appended, with `value` itself moved into the
+    /// last slot. If it shrinks, the surplus elements are dropped from the back.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        for _ in self.len + 1..new_len {
+            self.append(value.clone());
+        }
+        self.append(value);
+    }
+
+    /// Consumes the list and returns a new list with `f` applied to every element.
+    ///
+    /// Each element is moved out of its node before `f` is called, so a fresh node is
+    /// allocated for every mapped value.
+    pub fn map<U, F>(self, f: F) -> DoublyLinkedList<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Consumes the list and returns only the elements for which `pred` returns `true`.
+    ///
+    /// This is the consuming counterpart of [`retain`](Self::retain); rejected elements are
+    /// dropped and the surviving nodes are reused.
+    pub fn filter<F>(mut self, pred: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain(pred);
+        self
+    }
+
+    /// Consumes the list and splits it into the elements for which `pred` returns `true`
+    /// and those for which it returns `false`, preserving relative order in both.
+    pub fn partition<F>(mut self, mut pred: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut matched = Self::new();
+        let mut rest = Self::new();
+        while let Some(value) = self.pop_front() {
+            if pred(&value) {
+                matched.append(value);
+            } else {
+                rest.append(value);
+            }
+        }
+        (matched, rest)
+    }
+
+    /// Returns an iterator that removes and yields every element for which `pred` returns
+    /// `true`, visiting the list from head to tail exactly once.
+    ///
+    /// Elements for which `pred` returns `false` stay in the list in their original order.
+    /// If the iterator is dropped before it is exhausted, the elements it has not visited
+    /// yet are left in the list untouched.
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            cursor: self.cursor_front_mut(),
+            pred,
+        }
+    }
+
+    /// Consumes both lists and pairs up their elements from the front.
+    ///
+    /// The result is as long as the shorter list; leftover elements of the longer list are
+    /// dropped.
+    pub fn zip<U>(mut self, mut other: DoublyLinkedList<U>) -> DoublyLinkedList<(T, U)> {
+        let mut zipped = DoublyLinkedList::new();
+        while let (Some(a), Some(b)) = (self.pop_front(), other.pop_front()) {
+            zipped.append((a, b));
+        }
+        zipped
+    }
+
+    /// Consumes the list, maps every element to a list with `f` and concatenates the results.
+    ///
+    /// Each sub-list is joined with [`append_list`](Self::append_list) in O(1) time.
+    pub fn flat_map<U, F>(self, mut f: F) -> DoublyLinkedList<U>
+    where
+        F: FnMut(T) -> DoublyLinkedList<U>,
+    {
+        let mut flat = DoublyLinkedList::new();
+        for value in self {
+            flat.append_list(&mut f(value));
+        }
+        flat
+    }
+
+    /// Merges two sorted lists into one sorted list in O(n + m) time.
+    ///
+    /// See [`merge_sorted_by`](Self::merge_sorted_by) for details.
+    pub fn merge_sorted(self, other: Self) -> Self
+    where
+        T: Ord,
+    {
+        self.merge_sorted_by(other, T::cmp)
+    }
+
+    /// Merges two lists sorted by `cmp` into one sorted list in O(n + m) time.
+    ///
+    /// The merge is stable: when elements compare equal, those from `self` come first.
+    pub fn merge_sorted_by<F>(mut self, mut other: Self, mut cmp: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut merged = Self::new();
+        while let (Some(a), Some(b)) = (self.peek_front(), other.peek_front()) {
+            let value = if cmp(a, b) == Ordering::Greater {
+                other.pop_front()
+            } else {
+                self.pop_front()
+            };
+            merged.extend(value);
+        }
+        merged.append_list(&mut self);
+        merged.append_list(&mut other);
+        merged
+    }
+
+    /// Inserts `value` into a sorted list, keeping it sorted.
+    ///
+    /// See [`insert_sorted_by`](Self::insert_sorted_by) for details.
+    pub fn insert_sorted(&mut self, value: T)
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, T::cmp);
+    }
+
+    /// Inserts `value` into a list sorted by a key extraction function, keeping it sorted.
+    ///
+    /// See [`insert_sorted_by`](Self::insert_sorted_by) for details.
+    pub fn insert_sorted_by_key<K, F>(&mut self, value: T, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.insert_sorted_by(value, |a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Inserts `value` into a list sorted by `cmp`, keeping it sorted.
+    ///
+    /// The value is placed before the first element that compares greater than it, so it
+    /// ends up after any equal elements. This walks from the head and runs in O(n) time.
+    pub fn insert_sorted_by<F>(&mut self, value: T, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut next = self.head;
+        // SAFETY: `next` is null-checked before it is followed, and `prev` and `next` end up
+        // adjacent, as `link_between` requires.
+        unsafe {
+            while !next.is_null() && cmp(&(*next).value, &value) != Ordering::Greater {
+                next = (*next).next;
+            }
+            let prev = if next.is_null() {
+                self.tail
+            } else {
+                (*next).prev
+            };
+            self.link_between(prev, next, value);
+        }
+    }
+
+    /// Returns a reference to the smallest element, or `None` if the list is empty.
+    ///
+    /// If several elements are equally small, the first one is returned. This is not named
+    /// `min` because `Ord::min` on the list itself would take precedence in method calls.
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+
+    /// Returns a reference to the largest element, or `None` if the list is empty.
+    ///
+    /// If several elements are equally large, the last one is returned. This is not named
+    /// `max` because `Ord::max` on the list itself would take precedence in method calls.
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+
+    /// Returns a reference to the element with the smallest key, or `None` if the list is
+    /// empty. Ties resolve to the first such element.
+    pub fn min_by_key<K, F>(&self, mut key: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().min_by_key(|x| key(x))
+    }
+
+    /// Returns a reference to the element with the largest key, or `None` if the list is
+    /// empty. Ties resolve to the last such element.
+    pub fn max_by_key<K, F>(&self, mut key: F) -> Option<&T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.iter().max_by_key(|x| key(x))
+    }
+
+    /// Consumes the list and sums its elements.
+    ///
+    /// An empty list produces the additive identity of `S`.
+    pub fn sum<S: std::iter::Sum<T>>(self) -> S {
+        self.into_iter().sum()
+    }
+
+    /// Consumes the list and multiplies its elements together.
+    ///
+    /// An empty list produces the multiplicative identity of `P`.
+    pub fn product<P: std::iter::Product<T>>(self) -> P {
+        self.into_iter().product()
+    }
+
+    /// Removes and returns the first element if `pred` returns `true` for it.
+    ///
+    /// Returns `None` and leaves the list untouched if it is empty or `pred` returns `false`.
+    pub fn pop_if_front<F>(&mut self, pred: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        if pred(self.peek_front()?) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Removes and returns the last element if `pred` returns `true` for it.
+    ///
+    /// Returns `None` and leaves the list untouched if it is empty or `pred` returns `false`.
+    pub fn pop_if_back<F>(&mut self, pred: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        if pred(self.peek_back()?) {
+            self.pop_back()
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the internal links of the list are consistent.
+    ///
+    /// This walks the list from the head, checking that every `prev` pointer mirrors the
+    /// `next` pointer before it, that the ends are null-terminated, that the last node
+    /// reached is the tail and that the number of nodes matches `len`. The walk is capped
+    /// at `len` steps, so a cycle is reported as an inconsistency instead of hanging.
+    ///
+    /// Only available in builds with debug assertions enabled.
+    #[cfg(debug_assertions)]
+    pub fn is_consistent(&self) -> bool {
+        if self.head.is_null() || self.tail.is_null() {
+            return self.head.is_null() && self.tail.is_null() && self.len == 0;
+        }
+        // SAFETY: only pointers reachable from `head` are followed, each is null-checked, and the
+        // walk is capped at `len` steps.
+        unsafe {
+            let mut count = 0;
+            let mut prev = ptr::null_mut();
+            let mut current = self.head;
+            while !current.is_null() {
+                if count == self.len || (*current).prev != prev {
+                    return false;
+                }
+                prev = current;
+                current = (*current).next;
+                count += 1;
+            }
+            prev == self.tail && count == self.len
+        }
+    }
+
+    /// Panics if the internal links of the list are inconsistent.
+    ///
+    /// See [`is_consistent`](Self::is_consistent) for the checks performed. Only available
+    /// in builds with debug assertions enabled.
+    #[cfg(debug_assertions)]
+    pub fn assert_consistent(&self) {
+        assert!(
+            self.is_consistent(),
+            "doubly-linked list is inconsistent (len is {})",
+            self.len
+        );
+    }
+
+    /// Consumes the list and returns its elements as a `Vec`, in order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.into()
+    }
+
+    /// Returns a `Vec` containing clones of the elements, in order, leaving the list intact.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    /// Replaces the element at `index` with `value` and returns the old element.
+    ///
+    /// Returns `None`, dropping `value`, if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, value: T) -> Option<T> {
+        self.get_mut(index)
+            .map(|slot| std::mem::replace(slot, value))
+    }
+
+    /// Calls `f` with a mutable reference to every element, from head to tail.
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Consumes the list, folding every element into an accumulator from head to tail.
+    pub fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let mut acc = init;
+        while let Some(value) = self.pop_front() {
+            acc = f(acc, value);
+        }
+        acc
+    }
+
+    /// Consumes the list, folding every element into an accumulator from tail to head.
+    pub fn fold_right<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        let mut acc = init;
+        while let Some(value) = self.pop_back() {
+            acc = f(acc, value);
+        }
+        acc
+    }
+
+    /// Returns `true` if `pred` returns `true` for every element, stopping at the first
+    /// failure. An empty list returns `true`.
+    pub fn all<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.iter().all(pred)
+    }
+
+    /// Returns `true` if `pred` returns `true` for any element, stopping at the first
+    /// match. An empty list returns `false`.
+    pub fn any<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.iter().any(pred)
+    }
+
+    /// Returns the number of elements for which `pred` returns `true`.
+    pub fn count_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|x| pred(x)).count()
+    }
+
+    /// Consumes the list and reduces it to a single value by repeatedly applying `f` from
+    /// head to tail. Returns `None` if the list is empty.
+    pub fn reduce<F>(mut self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let first = self.pop_front()?;
+        Some(self.fold(first, f))
+    }
+
+    /// Removes the first `n` elements from the list, dropping them.
+    ///
+    /// If `n` is greater than or equal to the length, the list is cleared. The prefix is cut
+    /// off in one step and then freed, rather than being popped element by element.
+    pub fn truncate_front(&mut self, n: usize) {
+        let n = n.min(self.len);
+        drop(self.detach_range(0, n));
+    }
+
+    /// Removes the first `n` elements and returns them as a new list.
+    ///
+    /// If `n` is greater than the length, the whole list is taken.
+    pub fn take_front(&mut self, n: usize) -> Self {
+        let n = n.min(self.len);
+        self.detach_range(0, n)
+    }
+
+    /// Removes the last `n` elements and returns them as a new list.
+    ///
+    /// If `n` is greater than the length, the whole list is taken.
+    pub fn take_back(&mut self, n: usize) -> Self {
+        let n = n.min(self.len);
+        self.detach_range(self.len - n, self.len)
+    }
+
+    /// Returns a reference to the `n`th element counting from the head, or `None` if it is
+    /// out of bounds. This is the same as [`get`](Self::get).
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    /// Returns a reference to the `n`th element counting from the tail, or `None` if it is
+    /// out of bounds.
+    pub fn nth_back(&self, n: usize) -> Option<&T> {
+        if n >= self.len {
+            return None;
+        }
+        self.get(self.len - 1 - n)
+    }
+
+    /// Returns a mutable reference to the `n`th element counting from the head, or `None`
+    /// if it is out of bounds. This is the same as [`get_mut`](Self::get_mut).
+    pub fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.get_mut(n)
+    }
+
+    /// Returns a mutable reference to the `n`th element counting from the tail, or `None`
+    /// if it is out of bounds.
+    pub fn nth_back_mut(&mut self, n: usize) -> Option<&mut T> {
+        if n >= self.len {
+            return None;
+        }
+        self.get_mut(self.len - 1 - n)
+    }
+
+    /// Moves the first element to the back of the list in O(1) time without reallocating.
+    pub fn front_to_back(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+        // SAFETY: `len >= 2`, so `head`, its successor and `tail` are live and `head != tail`.
+        unsafe {
+            let node = self.head;
+            self.head = (*node).next;
+            (*self.head).prev = ptr::null_mut();
+            (*node).next = ptr::null_mut();
+            (*node).prev = self.tail;
+            (*self.tail).next = node;
+            self.tail = node;
+        }
+    }
+
+    /// Moves the last element to the front of the list in O(1) time without reallocating.
+    pub fn back_to_front(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+        // SAFETY: `len >= 2`, so `tail`, its predecessor and `head` are live and `head != tail`.
+        unsafe {
+            let node = self.tail;
+            self.tail = (*node).prev;
+            (*self.tail).next = ptr::null_mut();
+            (*node).prev = ptr::null_mut();
+            (*node).next = self.head;
+            (*self.head).prev = node;
+            self.head = node;
+        }
+    }
+
+    /// Consumes both lists and alternates their elements, starting with `self`.
+    ///
+    /// Once the shorter list runs out, the rest of the longer list is appended as is.
+    pub fn interleave(mut self, mut other: Self) -> Self {
+        let mut merged = Self::new();
+        while !self.is_empty() && !other.is_empty() {
+            merged.extend(self.pop_front());
+            merged.extend(other.pop_front());
+        }
+        merged.append_list(&mut self);
+        merged.append_list(&mut other);
+        merged
+    }
+
+    /// Moves the element at `from` so that it ends up at index `to`.
+    ///
+    /// `to` is interpreted after the element has been removed, so afterwards
+    /// `list.get(to)` is the moved element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_element(&mut self, from: usize, to: usize) {
+        for index in [from, to] {
+            assert!(
+                index < self.len,
+                "index out of bounds: the len is {} but the index is {}",
+                self.len,
+                index
+            );
+        }
+        if from == to {
+            return;
+        }
+        let value = self.remove_at(from).unwrap();
+        self.insert_at(to, value);
+    }
+
+    /// Pushes a value onto the back of the list, so it can be used as a stack.
+    ///
+    /// This is an alias for [`append`](Self::append), named after [`Vec::push`].
+    /// Together with [`pop`](Self::pop) it gives last-in, first-out order.
+    pub fn push(&mut self, value: T) {
+        self.append(value);
+    }
+
+    /// Pops the value most recently pushed, or `None` if the list is empty.
+    ///
+    /// This is an alias for [`pop_back`](Self::pop_back), named after [`Vec::pop`].
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    /// Adds a value to the back of the list, so it can be used as a queue.
+    ///
+    /// This is an alias for [`append`](Self::append). Together with
+    /// [`dequeue`](Self::dequeue) it gives first-in, first-out order.
+    ///
+    /// The stack and queue aliases all operate on the same list, so mixing
+    /// them is allowed: [`pop`](Self::pop) takes from the back while
+    /// `dequeue` takes from the front.
+    pub fn enqueue(&mut self, value: T) {
+        self.append(value);
+    }
+
+    /// Removes the value that has been queued the longest, or `None` if the list is empty.
+    ///
+    /// This is an alias for [`pop_front`](Self::pop_front).
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    /// Consumes the list and returns it sorted in ascending order.
+    ///
+    /// This is [`sort`](Self::sort) in a chainable form.
+    pub fn into_sorted(mut self) -> Self
+    where
+        T: Ord,
+    {
+        self.sort();
+        self
+    }
+
+    /// Consumes the list and returns it sorted with a comparator function.
+    ///
+    /// See [`sort_by`](Self::sort_by) for details.
+    pub fn into_sorted_by<F>(mut self, cmp: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.sort_by(cmp);
+        self
+    }
+
+    /// Consumes the list and returns it sorted with a key extraction function.
+    ///
+    /// See [`sort_by_key`](Self::sort_by_key) for details.
+    pub fn into_sorted_by_key<K, F>(mut self, key: F) -> Self
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by_key(key);
+        self
+    }
+
+    /// Returns a new list of the elements of `self` that do not appear in `other`.
+    ///
+    /// Elements keep the order they have in `self`, and duplicates in `self` are kept
+    /// unless they match something in `other`. Every element is compared against all
+    /// of `other`, so this runs in O(n * m) time.
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: PartialEq + Clone,
+    {
+        self.iter()
+            .filter(|x| !other.contains(x))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a new list of the elements of `self` that also appear in `other`.
+    ///
+    /// Like [`difference`](Self::difference), this preserves the order of `self` and
+    /// runs in O(n * m) time.
+    pub fn intersect(&self, other: &Self) -> Self
+    where
+        T: PartialEq + Clone,
+    {
+        self.iter().filter(|x| other.contains(x)).cloned().collect()
+    }
+
+    /// Converts the list into an array of exactly `N` elements.
+    ///
+    /// Returns the list unchanged in `Err` if its length is not `N`.
+    pub fn into_array<const N: usize>(mut self) -> Result<[T; N], Self> {
+        if self.len != N {
+            return Err(self);
+        }
+        let mut array = MaybeUninit::<[T; N]>::uninit();
+        let slots = array.as_mut_ptr() as *mut T;
+        for i in 0..N {
+            // The length check above guarantees exactly `N` elements to pop.
+            let value = self.pop_front().unwrap();
+            // SAFETY: `i < N`, so the slot is inside the array and has not been written yet.
+            unsafe { slots.add(i).write(value) };
+        }
+        // SAFETY: every slot was written by the loop above.
+        Ok(unsafe { array.assume_init() })
+    }
+
+    /// Returns a new list holding the string form of each element, in order.
+    pub fn to_string_list(&self) -> DoublyLinkedList<String>
+    where
+        T: ToString,
+    {
+        self.iter().map(T::to_string).collect()
+    }
+
+    /// Returns the node at `index`, walking from the head or the tail, whichever is closer.
+    ///
+    /// The caller must ensure that `index < self.len`.
+    fn node_at(&self, index: usize) -> *mut Node<T> {
+        debug_assert!(index < self.len);
+        // SAFETY: the caller guarantees `index < len`, so every step lands on a live node.
+        unsafe {
+            if index <= self.len / 2 {
+                let mut current = self.head;
+                for _ in 0..index {
+                    current = (*current).next;
+                }
+                current
+            } else {
+                let mut current = self.tail;
+                for _ in index + 1..self.len {
+                    current = (*current).prev;
+                }
+                current
+            }
+        }
+    }
+
+    /// Converts `range` into a half-open `(start, end)` pair, panicking if it is out of bounds.
+    fn resolve_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n
+                .checked_add(1)
+                .expect("attempted to index list from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n
+                .checked_add(1)
+                .expect("attempted to index list up to maximum usize"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end,
+            "list index starts at {} but ends at {}",
+            start,
+            end
+        );
+        assert!(
+            end <= self.len,
+            "range end index {} out of range for list of length {}",
+            end,
+            self.len
+        );
+        (start, end)
+    }
+
+    /// Unlinks the nodes in `start..end` and returns them as a new list.
+    ///
+    /// The caller must ensure that `start <= end <= self.len`.
+    fn detach_range(&mut self, start: usize, end: usize) -> Self {
+        let mut detached = Self::new();
+        if start == end {
+            return detached;
+        }
+        // SAFETY: `start < end <= len`, so the nodes at `start` and `end - 1` are live. Their outer
+        // neighbours are null-checked.
+        unsafe {
+            let first = self.node_at(start);
+            let last = self.node_at(end - 1);
+            let before = (*first).prev;
+            let after = (*last).next;
+            if before.is_null() {
+                self.head = after;
+            } else {
+                (*before).next = after;
+            }
+            if after.is_null() {
+                self.tail = before;
+            } else {
+                (*after).prev = before;
+            }
+            (*first).prev = ptr::null_mut();
+            (*last).next = ptr::null_mut();
+            detached.head = first;
+            detached.tail = last;
+        }
+        detached.len = end - start;
+        self.len -= detached.len;
+        detached
+    }
+
+    /// Links a new node holding `value` between `prev` and `next`.
+    ///
+    /// Either pointer may be null to insert at that end of the list. The caller must ensure
+    /// that the two nodes are adjacent in this list.
+    fn link_between(&mut self, prev: *mut Node<T>, next: *mut Node<T>, value: T) {
+        let new_node = Node::new(value);
+        // SAFETY: `new_node` was just allocated, and the caller guarantees that `prev` and `next`
+        // are adjacent nodes of this list or null.
+        unsafe {
+            (*new_node).prev = prev;
+            (*new_node).next = next;
+            if prev.is_null() {
+                self.head = new_node;
+            } else {
+                (*prev).next = new_node;
+            }
+            if next.is_null() {
+                self.tail = new_node;
+            } else {
+                (*next).prev = new_node;
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Moves every node of `other` in between `prev` and `next`, leaving `other` empty.
+    ///
+    /// Either pointer may be null to splice at that end of the list. The caller must ensure
+    /// that the two nodes are adjacent in this list.
+    fn splice_between(&mut self, prev: *mut Node<T>, next: *mut Node<T>, other: &mut Self) {
+        if other.head.is_null() {
+            return;
+        }
+        // SAFETY: `other` is non-empty, so its ends are live, and the caller guarantees that `prev`
+        // and `next` are adjacent nodes of this list or null.
+        unsafe {
+            (*other.head).prev = prev;
+            (*other.tail).next = next;
+            if prev.is_null() {
+                self.head = other.head;
+            } else {
+                (*prev).next = other.head;
+            }
+            if next.is_null() {
+                self.tail = other.tail;
+            } else {
+                (*next).prev = other.tail;
+            }
+        }
+        self.len += other.len;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+
+    /// Detaches `node` from the list, frees it and returns its value.
+    ///
+    /// The caller must ensure that `node` is a live node owned by this list.
+    fn unlink(&mut self, node: *mut Node<T>) -> T {
+        // SAFETY: the caller guarantees that `node` is a live node of this list, so the box can be
+        // reclaimed. Its neighbours are null-checked before they are relinked.
+        unsafe {
+            let node = Box::from_raw(node);
+            if node.prev.is_null() {
+                self.head = node.next;
+            } else {
+                (*node.prev).next = node.next;
+            }
+            if node.next.is_null() {
+                self.tail = node.prev;
+            } else {
+                (*node.next).prev = node.prev;
+            }
+            self.len -= 1;
+            node.value
+        }
+    }
+}
+
+impl<T> DoublyLinkedList<DoublyLinkedList<T>> {
+    /// Concatenates a list of lists into a single list.
+    ///
+    /// The inner lists are linked together with [`append_list`](DoublyLinkedList::append_list),
+    /// so the work is proportional to the number of inner lists rather than their elements.
+    pub fn flatten(self) -> DoublyLinkedList<T> {
+        let mut flat = DoublyLinkedList::new();
+        for mut inner in self {
+            flat.append_list(&mut inner);
+        }
+        flat
+    }
+}
+
+// SAFETY: the list exclusively owns every node it points to; no node is shared with another list
+// and there is no interior mutability, so sending the list sends its `T`s with it.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+
+// SAFETY: a shared `&DoublyLinkedList<T>` only ever hands out `&T`, so sharing the list across
+// threads is exactly as safe as sharing its elements.
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+// SAFETY: the shared iterators and `Cursor` only read through nodes of a list they borrow
+// immutably and only hand out `&T`, so they behave like `&DoublyLinkedList<T>`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Sync> Send for IterBack<'_, T> {}
+unsafe impl<T: Sync> Sync for IterBack<'_, T> {}
+unsafe impl<T: Sync> Send for Windows<'_, T> {}
+unsafe impl<T: Sync> Sync for Windows<'_, T> {}
+unsafe impl<T: Sync> Send for Chunks<'_, T> {}
+unsafe impl<T: Sync> Sync for Chunks<'_, T> {}
+unsafe impl<T: Sync> Send for Cursor<'_, T> {}
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+
+// SAFETY: the mutable iterators hand out `&mut T` to distinct nodes of a list they borrow
+// mutably, so they behave like `&mut DoublyLinkedList<T>`: sending one moves that access to
+// another thread, while a shared reference to one grants no access to the elements at all.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+unsafe impl<T: Send> Send for IterBackMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterBackMut<'_, T> {}
+
+// SAFETY: `CursorMut` holds the list's unique `&mut` borrow, so sending it is like sending
+// `&mut DoublyLinkedList<T>`. `ExtractIf` wraps a `CursorMut` and is `Send` when its predicate is.
+unsafe impl<T: Send> Send for CursorMut<'_, T> {}
+
+/// Ensures all nodes in the list are properly deallocated when the list goes out of scope.
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+/// Iterator for the doubly-linked list.
+pub struct Iter<'a, T> {
+    current: *mut Node<T>,
+    current_back: *mut Node<T>,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `current` is a node that has not been yielded from either
+        // end, and the list is borrowed for `'a`.
+        unsafe {
+            let node = &*self.current;
+            self.current = node.next;
+            self.remaining -= 1;
+            Some(&node.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `current_back` is a node that has not been yielded from
+        // either end, and the list is borrowed for `'a`.
+        unsafe {
+            let node = &*self.current_back;
+            self.current_back = node.prev;
+            self.remaining -= 1;
+            Some(&node.value)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+/// Mutable iterator for the doubly-linked list.
+pub struct IterMut<'a, T> {
+    current: *mut Node<T>,
+    current_back: *mut Node<T>,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `current` is live. Each node is yielded once from one end
+        // only, so the mutable references handed out never overlap.
+        unsafe {
+            let node = &mut *self.current;
+            self.current = node.next;
+            self.remaining -= 1;
+            Some(&mut node.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // SAFETY: `remaining > 0`, so `current_back` is live. Each node is yielded once from one
+        // end only, so the mutable references handed out never overlap.
+        unsafe {
+            let node = &mut *self.current_back;
+            self.current_back = node.prev;
+            self.remaining -= 1;
+            Some(&mut node.value)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+/// Consuming iterator for the doubly-linked list.
+pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.0.len
+    }
+}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+/// Reverse iterator for the doubly-linked list, starting from the tail.
+pub struct IterBack<'a, T> {
+    current: *mut Node<T>,
+    _marker: std::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for IterBack<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `current` is null-checked, and the list is borrowed for `'a`.
+        unsafe {
+            if self.current.is_null() {
+                None
+            } else {
+                let node = &*self.current;
+                self.current = node.prev;
+                Some(&node.value)
+            }
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for IterBack<'a, T> {}
+
+/// Mutable reverse iterator for the doubly-linked list, starting from the tail.
+pub struct IterBackMut<'a, T> {
+    current: *mut Node<T>,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterBackMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: `current` is null-checked, the list is mutably borrowed for `'a`, and no node is
+        // yielded twice.
+        unsafe {
+            if self.current.is_null() {
+                None
+            } else {
+                let node = &mut *self.current;
+                self.current = node.prev;
+                Some(&mut node.value)
+            }
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for IterBackMut<'a, T> {}
+
+/// Wrapper that iterates a borrowed list from the tail to the head.
+///
+/// ```rust
+/// use doubly_linked_list::{DoublyLinkedList, Rev};
+///
+/// let mut list = DoublyLinkedList::new();
+/// list.append(1);
+/// list.append(2);
+///
+/// for value in Rev(&list) {
+///     println!("{}", value);
+/// }
+/// ```
+pub struct Rev<'a, T>(pub &'a DoublyLinkedList<T>);
+
+/// Draining iterator returned by [`DoublyLinkedList::drain`].
+pub struct Drain<'a, T> {
+    inner: DoublyLinkedList<T>,
+    _marker: std::marker::PhantomData<&'a mut DoublyLinkedList<T>>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.len, Some(self.inner.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.pop_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len
+    }
+}
+
+impl<'a, T> FusedIterator for Drain<'a, T> {}
+
+/// Iterator returned by [`DoublyLinkedList::extract_if`].
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    cursor: CursorMut<'a, T>,
+    pred: F,
+}
+
+impl<'a, T, F> Iterator for ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(value) = self.cursor.current_mut() {
+            if (self.pred)(value) {
+                return self.cursor.remove_current();
+            }
+            self.cursor.move_next();
+        }
+        None
+    }
+}
+
+impl<'a, T, F> FusedIterator for ExtractIf<'a, T, F> where F: FnMut(&mut T) -> bool {}
+
+/// Iterator over overlapping windows of adjacent elements, returned by
+/// [`DoublyLinkedList::windows`].
+pub struct Windows<'a, T> {
+    current: *mut Node<T>,
+    size: usize,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let mut window = Vec::with_capacity(self.size);
+        // SAFETY: `remaining > 0` means at least `size` live nodes follow `current`, and the list
+        // is borrowed for `'a`.
+        unsafe {
+            let mut node = self.current;
+            for _ in 0..self.size {
+                window.push(&(*node).value);
+                node = (*node).next;
+            }
+            self.current = (*self.current).next;
+        }
+        self.remaining -= 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, T> FusedIterator for Windows<'a, T> {}
+
+/// Iterator over non-overlapping chunks of elements, returned by
+/// [`DoublyLinkedList::chunks`].
+pub struct Chunks<'a, T> {
+    current: *mut Node<T>,
+    size: usize,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let len = self.size.min(self.remaining);
+        let mut chunk = Vec::with_capacity(len);
+        // SAFETY: `remaining` counts the live nodes from `current` onwards, and the list is
+        // borrowed for `'a`.
+        unsafe {
+            for _ in 0..len {
+                chunk.push(&(*self.current).value);
+                self.current = (*self.current).next;
+            }
+        }
+        self.remaining -= len;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.remaining.div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {
+    fn len(&self) -> usize {
+        self.remaining.div_ceil(self.size)
+    }
+}
+
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+
+/// Allows the list to be iterated over.
+impl<T> DoublyLinkedList<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head,
+            current_back: self.tail,
+            remaining: self.len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            current: self.head,
+            current_back: self.tail,
+            remaining: self.len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the elements from `index` to the tail.
+    ///
+    /// The starting node is found by walking from whichever end is closer. If `index`
+    /// is out of bounds the iterator is empty rather than panicking, matching
+    /// [`get`](Self::get).
+    pub fn iter_from(&self, index: usize) -> Iter<'_, T> {
+        if index >= self.len {
+            return Iter {
+                current: ptr::null_mut(),
+                current_back: ptr::null_mut(),
+                remaining: 0,
+                _marker: std::marker::PhantomData,
+            };
+        }
+        Iter {
+            current: self.node_at(index),
+            current_back: self.tail,
+            remaining: self.len - index,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over every run of `size` adjacent elements, advancing one
+    /// element at a time. Each window is collected into a `Vec` of references.
+    ///
+    /// If the list is shorter than `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+        Windows {
+            current: self.head,
+            size,
+            remaining: (self.len + 1).saturating_sub(size),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over successive, non-overlapping chunks of `size` elements.
+    /// Each chunk is collected into a `Vec` of references; the last one may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks {
+            current: self.head,
+            size,
+            remaining: self.len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn iter_back(&self) -> IterBack<'_, T> {
+        IterBack {
+            current: self.tail,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    pub fn iter_back_mut(&mut self) -> IterBackMut<'_, T> {
+        IterBackMut {
+            current: self.tail,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns a reverse iterator over the elements from `index` back to the head.
+    ///
+    /// As with [`iter_from`](Self::iter_from), the starting node is found from the
+    /// closer end and an out-of-bounds `index` yields an empty iterator.
+    pub fn iter_back_from(&self, index: usize) -> IterBack<'_, T> {
+        let current = if index < self.len {
+            self.node_at(index)
+        } else {
+            ptr::null_mut()
+        };
+        IterBack {
+            current,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T> IntoIterator for Rev<'a, T> {
+    type Item = &'a T;
+    type IntoIter = IterBack<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_back()
+    }
+}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+/// A cursor over a borrowed list that can move back and forth between elements.
+///
+/// In addition to the elements of the list, a cursor can point to a "ghost" position
+/// that sits between the tail and the head. Moving past either end of the list lands
+/// on the ghost, and moving again from the ghost wraps around to the other end.
+pub struct Cursor<'a, T> {
+    current: *mut Node<T>,
+    index: usize,
+    list: &'a DoublyLinkedList<T>,
+}
+
+impl<'a, T> Clone for Cursor<'a, T> {
+    fn clone(&self) -> Self {
+        Cursor { ..*self }
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Moves the cursor to the next element, or to the ghost position after the tail.
+    ///
+    /// From the ghost position the cursor moves to the head.
+    pub fn move_next(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.head;
+            self.index = 0;
+        } else {
+            // SAFETY: `current` is non-null here, so it is a live node of the borrowed list.
+            unsafe {
+                self.current = (*self.current).next;
+            }
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous element, or to the ghost position before the head.
+    ///
+    /// From the ghost position the cursor moves to the tail.
+    pub fn move_prev(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.tail;
+            self.index = self.list.len.saturating_sub(1);
+        } else {
+            // SAFETY: `current` is non-null here, so it is a live node of the borrowed list.
+            unsafe {
+                self.current = (*self.current).prev;
+            }
+            self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+        }
+    }
+
+    /// Returns a reference to the element under the cursor, or `None` at the ghost position.
+    pub fn current(&self) -> Option<&'a T> {
+        // SAFETY: `current` is null or a live node of the list, which is borrowed for `'a`.
+        unsafe { self.current.as_ref().map(|node| &node.value) }
+    }
+
+    /// Returns the index of the element under the cursor, or `None` at the ghost position.
+    ///
+    /// The cursor keeps a counter that is updated on every move, so this runs in O(1)
+    /// time rather than counting predecessors.
+    pub fn index(&self) -> Option<usize> {
+        if self.current.is_null() {
+            None
+        } else {
+            Some(self.index)
+        }
+    }
+
+    /// Returns a reference to the element after the cursor.
+    ///
+    /// At the ghost position this is the head of the list.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        // SAFETY: `current` is null-checked, and `head` and every `next` pointer are null or live.
+        unsafe {
+            let next = if self.current.is_null() {
+                self.list.head
+            } else {
+                (*self.current).next
+            };
+            next.as_ref().map(|node| &node.value)
+        }
+    }
+
+    /// Returns a reference to the element before the cursor.
+    ///
+    /// At the ghost position this is the tail of the list.
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        // SAFETY: `current` is null-checked, and `tail` and every `prev` pointer are null or live.
+        unsafe {
+            let prev = if self.current.is_null() {
+                self.list.tail
+            } else {
+                (*self.current).prev
+            };
+            prev.as_ref().map(|node| &node.value)
+        }
+    }
+}
+
+/// A cursor over a mutably borrowed list that can also edit the list at its position.
+///
+/// Like [`Cursor`], it can point to a "ghost" position between the tail and the head.
+pub struct CursorMut<'a, T> {
+    current: *mut Node<T>,
+    index: usize,
+    list: &'a mut DoublyLinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Moves the cursor to the next element, or to the ghost position after the tail.
+    ///
+    /// From the ghost position the cursor moves to the head.
+    pub fn move_next(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.head;
+            self.index = 0;
+        } else {
+            // SAFETY: `current` is non-null here, so it is a live node of the borrowed list.
+            unsafe {
+                self.current = (*self.current).next;
+            }
+            self.index += 1;
+        }
+    }
+
+    /// Moves the cursor to the previous element, or to the ghost position before the head.
+    ///
+    /// From the ghost position the cursor moves to the tail.
+    pub fn move_prev(&mut self) {
+        if self.current.is_null() {
+            self.current = self.list.tail;
+            self.index = self.list.len.saturating_sub(1);
+        } else {
+            // SAFETY: `current` is non-null here, so it is a live node of the borrowed list.
+            unsafe {
+                self.current = (*self.current).prev;
+            }
+            self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+        }
+    }
+
+    /// Moves the cursor to the element at `index`, or to the ghost position if
+    /// `index == len`.
+    ///
+    /// The node is found by walking from whichever end of the list is closer, so this
+    /// runs in O(n) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn move_to_index(&mut self, index: usize) {
+        assert!(
+            index <= self.list.len,
+            "cursor index (is {}) should be <= len (is {})",
+            index,
+            self.list.len
+        );
+        self.current = if index == self.list.len {
+            ptr::null_mut()
+        } else {
+            self.list.node_at(index)
+        };
+        self.index = index;
+    }
+
+    /// Returns a reference to the element under the cursor, or `None` at the ghost position.
+    pub fn current(&self) -> Option<&T> {
+        // SAFETY: `current` is null or a live node, and the returned reference borrows the cursor.
+        unsafe { self.current.as_ref().map(|node| &node.value) }
+    }
+
+    /// Returns a mutable reference to the element under the cursor, or `None` at the ghost
+    /// position.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `current` is null or a live node, and the result borrows the cursor mutably.
+        unsafe { self.current.as_mut().map(|node| &mut node.value) }
+    }
+
+    /// Returns the index of the element under the cursor, or `None` at the ghost position.
+    ///
+    /// Like [`Cursor::index`], this runs in O(1) time and stays correct across insertions
+    /// and removals.
+    pub fn index(&self) -> Option<usize> {
+        if self.current.is_null() {
+            None
+        } else {
+            Some(self.index)
+        }
+    }
+
+    /// Returns a reference to the element after the cursor.
+    ///
+    /// At the ghost position this is the head of the list.
+    pub fn peek_next(&self) -> Option<&T> {
+        // SAFETY: `current` is null-checked, and `head` and every `next` pointer are null or live.
+        unsafe {
+            let next = if self.current.is_null() {
+                self.list.head
+            } else {
+                (*self.current).next
+            };
+            next.as_ref().map(|node| &node.value)
+        }
+    }
+
+    /// Returns a reference to the element before the cursor.
+    ///
+    /// At the ghost position this is the tail of the list.
+    pub fn peek_prev(&self) -> Option<&T> {
+        // SAFETY: `current` is null-checked, and `tail` and every `prev` pointer are null or live.
+        unsafe {
+            let prev = if self.current.is_null() {
+                self.list.tail
+            } else {
+                (*self.current).prev
+            };
+            prev.as_ref().map(|node| &node.value)
+        }
+    }
+
+    /// Inserts a value before the cursor.
+    ///
+    /// At the ghost position the value is inserted at the tail. The cursor keeps pointing
+    /// at the same element.
+    pub fn insert_before(&mut self, value: T) {
+        // SAFETY: `current` is null-checked, and `prev` and `current` are adjacent, as
+        // `link_between` requires.
+        unsafe {
+            let prev = if self.current.is_null() {
+                self.list.tail
+            } else {
+                (*self.current).prev
+            };
+            self.list.link_between(prev, self.current, value);
+        }
+        self.index += 1;
+    }
+
+    /// Inserts a value after the cursor.
+    ///
+    /// At the ghost position the value is inserted at the head. The cursor keeps pointing
+    /// at the same element.
+    pub fn insert_after(&mut self, value: T) {
+        // SAFETY: `current` is null-checked, and `current` and `next` are adjacent, as
+        // `link_between` requires.
+        unsafe {
+            let next = if self.current.is_null() {
+                self.list.head
+            } else {
+                (*self.current).next
+            };
+            self.list.link_between(self.current, next, value);
+        }
+        if self.current.is_null() {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Removes the element under the cursor and returns it, moving the cursor to the next
+    /// element.
+    ///
+    /// Returns `None` and leaves the list untouched at the ghost position.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+        let node = self.current;
+        // SAFETY: `node` was checked to be non-null, so it is a live node of the list.
+        unsafe {
+            self.current = (*node).next;
+        }
+        Some(self.list.unlink(node))
+    }
+
+    /// Moves every element of `other` in before the cursor, leaving `other` empty.
+    ///
+    /// At the ghost position the elements are spliced in at the tail. The cursor keeps
+    /// pointing at the same element.
+    pub fn splice_before(&mut self, other: &mut DoublyLinkedList<T>) {
+        let spliced = other.len;
+        // SAFETY: `current` is null-checked, and `prev` and `current` are adjacent, as
+        // `splice_between` requires.
+        unsafe {
+            let prev = if self.current.is_null() {
+                self.list.tail
+            } else {
+                (*self.current).prev
+            };
+            self.list.splice_between(prev, self.current, other);
+        }
+        self.index += spliced;
+    }
+
+    /// Moves every element of `other` in after the cursor, leaving `other` empty.
+    ///
+    /// At the ghost position the elements are spliced in at the head. The cursor keeps
+    /// pointing at the same element.
+    pub fn splice_after(&mut self, other: &mut DoublyLinkedList<T>) {
+        // SAFETY: `current` is null-checked, and `current` and `next` are adjacent, as
+        // `splice_between` requires.
+        unsafe {
+            let next = if self.current.is_null() {
+                self.list.head
+            } else {
+                (*self.current).next
+            };
+            self.list.splice_between(self.current, next, other);
+        }
+        if self.current.is_null() {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Splits the list after the cursor and returns everything after it as a new list.
+    ///
+    /// At the ghost position the whole list is returned and the original is left empty.
+    pub fn split_after(&mut self) -> DoublyLinkedList<T> {
+        if self.current.is_null() {
+            self.index = 0;
+            return std::mem::take(self.list);
+        }
+        let mut other = DoublyLinkedList::new();
+        // SAFETY: `current` is non-null, so it and its successor, if any, are live.
+        unsafe {
+            let next = (*self.current).next;
+            if !next.is_null() {
+                (*next).prev = ptr::null_mut();
+                (*self.current).next = ptr::null_mut();
+                other.head = next;
+                other.tail = self.list.tail;
+                other.len = self.list.len - self.index - 1;
+                self.list.tail = self.current;
+                self.list.len = self.index + 1;
+            }
+        }
+        other
+    }
+}
+
+/// Cursors over the list.
+impl<T> DoublyLinkedList<T> {
+    /// Returns a cursor pointing at the head, or at the ghost position if the list is empty.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the tail, or at the ghost position if the list is empty.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    /// Returns a mutable cursor pointing at the head, or at the ghost position if the list is
+    /// empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Returns a mutable cursor pointing at the tail, or at the ghost position if the list is
+    /// empty.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+}
+
+/// Creates an empty list, equivalent to `DoublyLinkedList::new()`.
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Performs a deep copy of the list, allocating a new node for every element.
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new();
+        for value in self {
+            list.append(value.clone());
+        }
+        list
+    }
+}
+
+/// Formats the list as `[a, b, c]`, walking from head to tail.
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Formats the list as `[a, b, c]` using each element's `Display` implementation.
+impl<T: fmt::Display> fmt::Display for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Two lists are equal when they have the same length and equal elements in the same order.
+impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DoublyLinkedList<T> {}
+
+impl<T: PartialEq> PartialEq<[T]> for DoublyLinkedList<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.len == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for DoublyLinkedList<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for DoublyLinkedList<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq> PartialEq<DoublyLinkedList<T>> for [T] {
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        *other == *self
+    }
+}
+
+impl<T: PartialEq> PartialEq<DoublyLinkedList<T>> for &[T] {
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        *other == **self
+    }
+}
+
+impl<T: PartialEq> PartialEq<DoublyLinkedList<T>> for Vec<T> {
+    fn eq(&self, other: &DoublyLinkedList<T>) -> bool {
+        *other == **self
+    }
+}
+
+/// Lists are compared lexicographically, so a list that is a prefix of another is `Less`.
+impl<T: PartialOrd> PartialOrd for DoublyLinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for DoublyLinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length followed by every element in forward order, consistent with `PartialEq`.
+impl<T: Hash> Hash for DoublyLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+/// Builds a list by appending every item of the iterator in order.
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for value in iter {
+            list.append(value);
+        }
+        list
+    }
+}
+
+/// Appends every item of the iterator to the back of the list.
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Moves every element out of the vector into a new list, preserving order.
+impl<T> From<Vec<T>> for DoublyLinkedList<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+/// Moves every element out of the array into a new list, preserving order.
+impl<T, const N: usize> From<[T; N]> for DoublyLinkedList<T> {
+    fn from(array: [T; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+/// Clones every element of the slice into a new list, preserving order.
+impl<T: Clone> From<&[T]> for DoublyLinkedList<T> {
+    fn from(s: &[T]) -> Self {
+        Self::from_slice(s)
+    }
+}
+
+/// Parses a comma-separated list such as `"1, 2, 3"`, trimming whitespace around each item.
+///
+/// An empty (or all-whitespace) string parses to an empty list. Parsing stops at the first
+/// item that fails, returning its error.
+impl<T: FromStr> FromStr for DoublyLinkedList<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(Self::new());
+        }
+        s.split(',').map(|item| item.trim().parse()).collect()
+    }
+}
+
+/// Moves every element out of the list into a new vector, preserving order.
+impl<T> From<DoublyLinkedList<T>> for Vec<T> {
+    fn from(list: DoublyLinkedList<T>) -> Self {
+        let mut vec = Vec::with_capacity(list.len());
+        vec.extend(list);
+        vec
+    }
+}
+
+/// Panicking element access by position, walking from whichever end is closer.
+impl<T> Index<usize> for DoublyLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for DoublyLinkedList<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+        }
+    }
+}
+
+/// Serializes the list as a sequence, from head to tail.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for DoublyLinkedList<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for value in self {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes the list from a sequence, appending elements in order.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for DoublyLinkedList<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SeqVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for SeqVisitor<T> {
+            type Value = DoublyLinkedList<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut list = DoublyLinkedList::new();
+                while let Some(value) = seq.next_element()? {
+                    list.append(value);
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(SeqVisitor(std::marker::PhantomData))
+    }
+}
+
+/// Generates a list by appending arbitrary elements until the input runs out.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for DoublyLinkedList<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut list = Self::new();
+        for value in u.arbitrary_iter()? {
+            list.append(value?);
+        }
+        Ok(list)
+    }
+}
+
+/// Iterates the elements in parallel.
+///
+/// A list has no random access, so the elements are first moved into a `Vec` in O(n)
+/// time and that is split between threads.
+#[cfg(feature = "rayon")]
+impl<T: Send> rayon::iter::IntoParallelIterator for DoublyLinkedList<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_vec().into_par_iter()
+    }
+}
+
+/// Iterates references to the elements in parallel, after collecting them in O(n) time.
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync> rayon::iter::IntoParallelIterator for &'a DoublyLinkedList<T> {
+    type Iter = rayon::vec::IntoIter<&'a T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+/// Concatenates two lists by linking them together in O(1) time.
+impl<T> Add for DoublyLinkedList<T> {
+    type Output = Self;
+
+    fn add(mut self, mut other: Self) -> Self::Output {
+        self.append_list(&mut other);
+        self
+    }
+}
+
+/// Moves every element of `other` to the end of the list in O(1) time.
+impl<T> AddAssign for DoublyLinkedList<T> {
+    fn add_assign(&mut self, mut other: Self) {
+        self.append_list(&mut other);
+    }
+}
+
+/// Writing to a byte list appends to its back, so headers can still be prepended afterwards.
+impl io::Write for DoublyLinkedList<u8> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = DoublyLinkedList::new();
+        list.prepend(3);
+        list.prepend(2);
+        list.prepend(1);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_empty_list() {
+        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_iteration() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_pop_single_element() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        assert_eq!(list.pop_back(), Some(1));
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+
+        list.append(2);
+        list.prepend(1);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut list = DoublyLinkedList::new();
+        assert_eq!(list.peek_front(), None);
+        assert_eq!(list.peek_back(), None);
+
+        list.append(1);
+        assert_eq!(list.peek_front(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&1));
+
+        list.append(2);
+        list.prepend(0);
+        assert_eq!(list.peek_front(), Some(&0));
+        assert_eq!(list.peek_back(), Some(&2));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut list = DoublyLinkedList::new();
+        assert_eq!(list.peek_front_mut(), None);
+        assert_eq!(list.peek_back_mut(), None);
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        if let Some(value) = list.peek_front_mut() {
+            *value += 10;
+        }
+        if let Some(value) = list.peek_back_mut() {
+            *value *= 10;
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&11));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&30));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        for value in list.iter_mut() {
+            *value *= 2;
+        }
+        for value in &mut list {
+            *value += 1;
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&7));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = DoublyLinkedList::new();
+        list.append(String::from("a"));
+        list.append(String::from("b"));
+        list.append(String::from("c"));
+
+        let mut values = Vec::new();
+        for value in list {
+            values.push(value);
+        }
+        assert_eq!(values, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_into_iter_partial_drop() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut list = DoublyLinkedList::new();
+        for _ in 0..4 {
+            list.append(Rc::clone(&tracker));
+        }
+        assert_eq!(Rc::strong_count(&tracker), 5);
+
+        let mut iter = list.into_iter();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&tracker), 4);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let reversed: Vec<_> = list.iter().rev().collect();
+        assert_eq!(reversed, vec![&3, &2, &1]);
+
+        let pairs: Vec<_> = list.iter().zip(list.iter().rev()).collect();
+        assert_eq!(pairs, vec![(&1, &3), (&2, &2), (&3, &1)]);
+
+        for value in list.iter_mut().rev() {
+            *value *= 10;
+        }
+        let values: Vec<_> = list.into_iter().rev().collect();
+        assert_eq!(values, vec![30, 20, 10]);
+    }
+
+    #[test]
+    fn test_iter_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        for i in 1..=5 {
+            list.append(i);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next_back(), Some(&mut 5));
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next_back(), Some(&mut 4));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next_back(), Some(&mut 3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_exact_size() {
+        let mut list = DoublyLinkedList::new();
+        for i in 1..=4 {
+            list.append(i);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        assert_eq!(iter.len(), 3);
+        iter.next_back();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = list.iter_mut();
+        iter.next_back();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 4);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 2);
+    }
+
+    #[test]
+    fn test_fused() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+
+        let mut iter = list.iter();
+        iter.next();
+        iter.next_back();
+        for _ in 0..32 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        let mut iter = list.iter_mut();
+        iter.next();
+        iter.next();
+        for _ in 0..32 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        let mut iter = list.into_iter();
+        iter.next_back();
+        iter.next_back();
+        for _ in 0..32 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+
+    #[test]
+    fn test_iter_back() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let forward: Vec<_> = list.iter().collect();
+        let mut backward: Vec<_> = list.iter_back().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+
+        let wrapped: Vec<_> = Rev(&list).into_iter().collect();
+        assert_eq!(wrapped, vec![&3, &2, &1]);
+
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.iter_back().next(), None);
+    }
+
+    #[test]
+    fn test_iter_back_mut() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let mut offset = 0;
+        for value in list.iter_back_mut() {
+            offset += 10;
+            *value += offset;
+        }
+
+        let values: Vec<_> = list.iter().collect();
+        assert_eq!(values, vec![&31, &22, &13]);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut list = DoublyLinkedList::new();
+        list.append(String::from("a"));
+        list.append(String::from("b"));
+
+        let mut copy = list.clone();
+        assert_eq!(copy.len(), 2);
+        copy.append(String::from("c"));
+        if let Some(value) = copy.peek_front_mut() {
+            value.push('!');
+        }
+
+        let original: Vec<_> = list.iter().collect();
+        assert_eq!(original, vec!["a", "b"]);
+        let cloned: Vec<_> = copy.iter().collect();
+        assert_eq!(cloned, vec!["a!", "b", "c"]);
+    }
+
+    #[test]
+    fn test_clone_empty() {
+        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let copy = list.clone();
+        assert!(copy.is_empty());
+        assert!(copy.head.is_null());
+        assert!(copy.tail.is_null());
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut list = DoublyLinkedList::new();
+        assert_eq!(format!("{:?}", list), "[]");
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:#?}", list), "[\n    1,\n    2,\n    3,\n]");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut list = DoublyLinkedList::new();
+        assert_eq!(format!("{}", list), "[]");
+
+        list.append("a");
+        assert_eq!(format!("{}", list), "[a]");
+
+        list.append("b");
+        list.append("c");
+        assert_eq!(format!("{}", list), "[a, b, c]");
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut a = DoublyLinkedList::new();
+        let mut b = DoublyLinkedList::new();
+        assert_eq!(a, b);
+
+        for i in 1..=3 {
+            a.append(i);
+            b.append(i);
+        }
+        assert_eq!(a, b);
+
+        b.append(4);
+        assert_ne!(a, b);
+
+        b.pop_back();
+        b.pop_back();
+        b.append(4);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_eq_sequences() {
+        let mut list = DoublyLinkedList::new();
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3].as_slice());
+        assert_eq!(list, *[1, 2, 3].as_slice());
+        assert_ne!(list, vec![1, 2]);
+        assert_ne!(list, vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut a = DoublyLinkedList::new();
+        let mut b = DoublyLinkedList::new();
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        b.append(1);
+        assert!(a < b);
+
+        a.append(1);
+        a.append(2);
+        b.append(3);
+        assert!(a < b);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
+
+        b.pop_back();
+        assert!(b < a);
+    }
+
+    #[test]
+    fn test_ord_btree() {
+        use std::collections::BTreeSet;
+
+        let mut set = BTreeSet::new();
+        for values in [vec![2, 1], vec![1, 2, 3], vec![1, 2]] {
+            let mut list = DoublyLinkedList::new();
+            for value in values {
+                list.append(value);
+            }
+            set.insert(list);
+        }
+
+        let ordered: Vec<_> = set.iter().map(|list| list.len()).collect();
+        assert_eq!(ordered, vec![2, 3, 2]);
+        assert_eq!(set.first().unwrap(), &vec![1, 2]);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashMap;
+
+        let mut a = DoublyLinkedList::new();
+        a.append("x");
+        a.append("y");
+        let mut b = DoublyLinkedList::new();
+        b.prepend("y");
+        b.prepend("x");
+
+        let mut map = HashMap::new();
+        map.insert(a, 1);
+        map.insert(b, 2);
+        assert_eq!(map.len(), 1);
+
+        let mut key = DoublyLinkedList::new();
+        key.append("x");
+        key.append("y");
+        assert_eq!(map.get(&key), Some(&2));
+    }
+
+    #[test]
+    fn test_default() {
+        let list: DoublyLinkedList<i32> = Default::default();
+        assert!(list.is_empty());
+
+        #[derive(Default)]
+        struct Holder {
+            list: DoublyLinkedList<String>,
+        }
+        assert!(Holder::default().list.is_empty());
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list: DoublyLinkedList<_> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(list, vec![1, 2, 3]);
+
+        let list: DoublyLinkedList<_> = (0..5).collect();
+        assert_eq!(list.len(), 5);
+        assert_eq!(list, vec![0, 1, 2, 3, 4]);
+
+        let list: DoublyLinkedList<_> = (0..10).filter(|x| x % 3 == 0).collect();
+        assert_eq!(list, vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list: DoublyLinkedList<_> = (1..=2).collect();
+        list.extend(vec![3, 4]);
+        assert_eq!(list, vec![1, 2, 3, 4]);
+
+        list.extend(&[5, 6]);
+        assert_eq!(list, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.peek_back(), Some(&6));
+    }
+
+    #[test]
+    fn test_extend_front() {
+        let mut list: DoublyLinkedList<_> = (4..=5).collect();
+        list.extend_front(vec![1, 2, 3]);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+
+        let backward: Vec<_> = list.iter().rev().copied().collect();
+        assert_eq!(backward, vec![5, 4, 3, 2, 1]);
+
+        list.extend_front(Vec::new());
+        assert_eq!(list.len(), 5);
+
+        let mut empty = DoublyLinkedList::new();
+        empty.extend_front(1..=2);
+        assert_eq!(empty, vec![1, 2]);
+        assert_eq!(empty.peek_back(), Some(&2));
+    }
+
+    #[test]
+    fn test_vec_round_trip() {
+        let original = vec![String::from("a"), String::from("b"), String::from("c")];
+        let list = DoublyLinkedList::from(original.clone());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list, original);
+
+        let back: Vec<String> = list.into();
+        assert_eq!(back, original);
+
+        let empty: Vec<i32> = DoublyLinkedList::from(Vec::new()).into();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let values = [1, 2, 3, 4, 5];
+        let list = DoublyLinkedList::from_slice(&values);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+
+        let list = DoublyLinkedList::from(&values[1..3]);
+        assert_eq!(list, vec![2, 3]);
+
+        let list = DoublyLinkedList::from(&values[..0]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_from_array() {
+        let list = DoublyLinkedList::<i32>::from([]);
+        assert!(list.is_empty());
+
+        let list = DoublyLinkedList::from([1]);
+        assert_eq!(list, vec![1]);
+
+        let list = DoublyLinkedList::from([1, 2]);
+        assert_eq!(list, vec![1, 2]);
+
+        let list = DoublyLinkedList::from([0; 16]);
+        assert_eq!(list.len(), 16);
+
+        let list = DoublyLinkedList::from([String::from("a"), String::from("b")]);
+        assert_eq!(list.peek_back().map(String::as_str), Some("b"));
+    }
+
+    #[test]
+    fn test_get() {
+        let list = DoublyLinkedList::from([10, 20, 30, 40, 50]);
+        assert_eq!(list.get(0), Some(&10));
+        assert_eq!(list.get(2), Some(&30));
+        assert_eq!(list.get(3), Some(&40));
+        assert_eq!(list.get(4), Some(&50));
+        assert_eq!(list.get(5), None);
+
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.get(0), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list = DoublyLinkedList::from([10, 20, 30, 40, 50]);
+        *list.get_mut(0).unwrap() += 1;
+        *list.get_mut(2).unwrap() += 2;
+        *list.get_mut(4).unwrap() += 3;
+        assert_eq!(list.get_mut(5), None);
+        assert_eq!(list, vec![11, 20, 32, 40, 53]);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        assert_eq!(list[0], 1);
+        assert_eq!(list[2], 3);
+
+        list[0] = 10;
+        list[1] *= 5;
+        assert_eq!(list, vec![10, 10, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 3 but the index is 3")]
+    fn test_index_out_of_bounds() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let _ = list[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 0 but the index is 0")]
+    fn test_index_mut_out_of_bounds() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        list[0] = 1;
+    }
+
+    #[test]
+    fn test_insert_at() {
+        for index in 0..=3 {
+            let mut list = DoublyLinkedList::from([0, 1, 2]);
+            list.insert_at(index, 9);
+            assert_eq!(list.len(), 4);
+
+            let mut expected = vec![0, 1, 2];
+            expected.insert(index, 9);
+            assert_eq!(list, expected);
+
+            let backward: Vec<_> = list.iter_back().copied().collect();
+            expected.reverse();
+            assert_eq!(backward, expected);
+        }
+
+        let mut list = DoublyLinkedList::new();
+        list.insert_at(0, 1);
+        assert_eq!(list, vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
+    fn test_insert_at_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([0, 1, 2]);
+        list.insert_at(4, 9);
+    }
+
+    #[test]
+    fn test_remove_at() {
+        for len in 1..=4 {
+            for index in 0..len {
+                let mut list: DoublyLinkedList<_> = (0..len).collect();
+                assert_eq!(list.remove_at(index), Some(index));
+                assert_eq!(list.len(), len - 1);
+
+                let expected: Vec<_> = (0..len).filter(|&x| x != index).collect();
+                assert_eq!(list, expected);
+                let backward: Vec<_> = list.iter_back().copied().collect();
+                assert_eq!(backward, expected.into_iter().rev().collect::<Vec<_>>());
+            }
+        }
+
+        let mut list = DoublyLinkedList::from([1, 2]);
+        assert_eq!(list.remove_at(2), None);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_clear_stays_consistent_when_drop_panics() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        struct PanicOnDrop(bool);
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if self.0 {
+                    self.0 = false;
+                    panic!("drop panicked");
+                }
+            }
+        }
+
+        let mut list = DoublyLinkedList::new();
+        list.append(PanicOnDrop(false));
+        list.append(PanicOnDrop(true));
+        list.append(PanicOnDrop(false));
+        assert!(catch_unwind(AssertUnwindSafe(|| list.clear())).is_err());
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.iter().count(), 1);
+        assert!(list.get(0).is_some());
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_clear() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut list: DoublyLinkedList<_> = (0..3).map(|_| Rc::clone(&tracker)).collect();
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.peek_front(), None);
+        assert_eq!(list.peek_back(), None);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+
+        list.append(Rc::clone(&tracker));
+        list.prepend(Rc::clone(&tracker));
+        assert_eq!(list.len(), 2);
+        drop(list);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_contains() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        assert!(list.contains(&1));
+        assert!(list.contains(&2));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+
+        let floats = DoublyLinkedList::from([0.5, f64::NAN, 1.5]);
+        assert!(floats.contains(&1.5));
+        assert!(!floats.contains(&f64::NAN));
+    }
+
+    #[test]
+    fn test_position() {
+        let list = DoublyLinkedList::from([1, 2, 3, 2, 5]);
+        assert_eq!(list.position(|&x| x == 1), Some(0));
+        assert_eq!(list.position(|&x| x == 5), Some(4));
+        assert_eq!(list.position(|&x| x == 2), Some(1));
+        assert_eq!(list.position(|&x| x > 9), None);
+
+        assert_eq!(list.rposition(|&x| x == 1), Some(0));
+        assert_eq!(list.rposition(|&x| x == 5), Some(4));
+        assert_eq!(list.rposition(|&x| x == 2), Some(3));
+        assert_eq!(list.rposition(|&x| x > 9), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.find(|&x| x % 2 == 0), Some(&2));
+        assert_eq!(list.find(|&x| x > 9), None);
+
+        if let Some(value) = list.find_mut(|&x| x == 3) {
+            *value = 30;
+        }
+        assert_eq!(list.find_mut(|&x| x > 99), None);
+        assert_eq!(list, vec![1, 2, 30, 4]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4]);
+        list.reverse();
+        assert_eq!(list, vec![4, 3, 2, 1]);
+        let backward: Vec<_> = list.iter().rev().copied().collect();
+        assert_eq!(backward, vec![1, 2, 3, 4]);
+        assert_eq!(list.peek_front(), Some(&4));
+        assert_eq!(list.peek_back(), Some(&1));
+
+        list.reverse();
+        list.reverse();
+        assert_eq!(list, vec![4, 3, 2, 1]);
+
+        let mut single = DoublyLinkedList::from([1]);
+        single.reverse();
+        assert_eq!(single, vec![1]);
+
+        let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list = DoublyLinkedList::from([5, 3, 8, 1, 9, 2, 7]);
+        list.sort();
+        assert_eq!(list, vec![1, 2, 3, 5, 7, 8, 9]);
+        assert_eq!(list.peek_back(), Some(&9));
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![9, 8, 7, 5, 3, 2, 1]);
+
+        let mut sorted: DoublyLinkedList<_> = (0..20).collect();
+        sorted.sort();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+
+        let mut reversed: DoublyLinkedList<_> = (0..33).rev().collect();
+        reversed.sort();
+        assert_eq!(reversed, (0..33).collect::<Vec<_>>());
+        assert_eq!(reversed.len(), 33);
+
+        let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        empty.sort();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_keeps_elements_when_cmp_panics() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        // Panic at every possible comparison, so that each pass and merge position is exercised.
+        for panic_at in 1..20 {
+            let mut list: DoublyLinkedList<_> = [5, 2, 7, 0, 6, 1, 4, 3]
+                .map(|i| (i, Rc::clone(&tracker)))
+                .into();
+            let mut calls = 0;
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    calls += 1;
+                    assert!(calls != panic_at, "comparator panicked");
+                    a.0.cmp(&b.0)
+                })
+            }));
+            assert_eq!(result.is_err(), panic_at <= calls);
+            assert_eq!(list.len(), 8);
+            assert_eq!(list.iter().count(), 8);
+            assert_eq!(list.iter_back().count(), 8);
+            let mut values: Vec<_> = list.iter().map(|&(i, _)| i).collect();
+            values.sort();
+            assert_eq!(values, (0..8).collect::<Vec<_>>());
+            assert_eq!(Rc::strong_count(&tracker), 9);
+
+            list.sort_by_key(|&(i, _)| i);
+            let sorted: Vec<_> = list.iter().map(|&(i, _)| i).collect();
+            assert_eq!(sorted, (0..8).collect::<Vec<_>>());
+            drop(list);
+            assert_eq!(Rc::strong_count(&tracker), 1);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_stable() {
+        let mut list = DoublyLinkedList::from([(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]);
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(list, vec![(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+
+        list.sort_by(|a, b| b.1.cmp(&a.1));
+        let letters: String = list.iter().map(|&(_, c)| c).collect();
+        assert_eq!(letters, "edcba");
+    }
+
+    #[test]
+    fn test_sort_by_key() {
+        let mut words = DoublyLinkedList::from(["ccc", "a", "bb", "dd", ""]);
+        words.sort_by_key(|s| s.len());
+        assert_eq!(words, vec!["", "a", "bb", "dd", "ccc"]);
+
+        let mut pairs = DoublyLinkedList::from([(1, 'z'), (2, 'x'), (3, 'y')]);
+        pairs.sort_by_key(|&(_, c)| c);
+        assert_eq!(pairs, vec![(2, 'x'), (3, 'y'), (1, 'z')]);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = DoublyLinkedList::from([1, 1, 2, 3, 3, 3, 4, 4]);
+        list.dedup();
+        assert_eq!(list, vec![1, 2, 3, 4]);
+        assert_eq!(list.peek_back(), Some(&4));
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![4, 3, 2, 1]);
+
+        let mut same = DoublyLinkedList::from([7, 7, 7]);
+        same.dedup();
+        assert_eq!(same, vec![7]);
+
+        let mut distinct = DoublyLinkedList::from([1, 2, 1]);
+        distinct.dedup();
+        assert_eq!(distinct, vec![1, 2, 1]);
+
+        let mut single = DoublyLinkedList::from([1]);
+        single.dedup();
+        assert_eq!(single, vec![1]);
+
+        let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        let mut list = DoublyLinkedList::from(["a", "A", "b", "B", "b", "c"]);
+        list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        assert_eq!(list, vec!["a", "b", "c"]);
+
+        let mut list = DoublyLinkedList::from([10, 11, 20, 25, 31]);
+        list.dedup_by_key(|x| *x / 10);
+        assert_eq!(list, vec![10, 20, 31]);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        list.retain(|&x| x % 2 == 0);
+        assert_eq!(list, vec![0, 2, 4]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![4, 2, 0]);
+
+        list.retain(|_| true);
+        assert_eq!(list, vec![0, 2, 4]);
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_front(), None);
+        assert_eq!(list.peek_back(), None);
+    }
+
+    #[test]
+    fn test_retain_mut() {
+        let mut list: DoublyLinkedList<_> = (1..=5).collect();
+        list.retain_mut(|x| {
+            *x *= 10;
+            *x != 30
+        });
+        assert_eq!(list, vec![10, 20, 40, 50]);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        let drained: Vec<_> = list.drain(..).collect();
+        assert_eq!(drained, vec![0, 1, 2, 3, 4, 5]);
+        assert!(list.is_empty());
+
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        let drained: Vec<_> = list.drain(..3).collect();
+        assert_eq!(drained, vec![0, 1, 2]);
+        assert_eq!(list, vec![3, 4, 5]);
+
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        let drained: Vec<_> = list.drain(3..).collect();
+        assert_eq!(drained, vec![3, 4, 5]);
+        assert_eq!(list, vec![0, 1, 2]);
+        assert_eq!(list.peek_back(), Some(&2));
+
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        let drained: Vec<_> = list.drain(2..=3).collect();
+        assert_eq!(drained, vec![2, 3]);
+        assert_eq!(list, vec![0, 1, 4, 5]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![5, 4, 1, 0]);
+
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        let drained: Vec<_> = list.drain(4..5).collect();
+        assert_eq!(drained, vec![4]);
+        assert_eq!(list, vec![0, 1, 2, 3, 5]);
+
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        assert_eq!(list.drain(2..2).next(), None);
+        assert_eq!(list.len(), 6);
+    }
+
+    #[test]
+    fn test_drain_partial() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut list: DoublyLinkedList<_> = (0..5).map(|_| Rc::clone(&tracker)).collect();
+        let mut drain = list.drain(1..4);
+        assert_eq!(drain.len(), 3);
+        drop(drain.next_back());
+        drop(drain);
+        assert_eq!(list.len(), 2);
+        assert_eq!(Rc::strong_count(&tracker), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 4 out of range for list of length 3")]
+    fn test_drain_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.drain(1..4);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list: DoublyLinkedList<_> = (0..5).collect();
+        let other = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(other, vec![0, 1, 2, 3, 4]);
+
+        let mut list: DoublyLinkedList<_> = (0..5).collect();
+        let other = list.split_off(5);
+        assert_eq!(list, vec![0, 1, 2, 3, 4]);
+        assert!(other.is_empty());
+
+        let mut list: DoublyLinkedList<_> = (0..5).collect();
+        let other = list.split_off(2);
+        assert_eq!(list, vec![0, 1]);
+        assert_eq!(other, vec![2, 3, 4]);
+        assert_eq!(list.peek_back(), Some(&1));
+        assert_eq!(other.peek_front(), Some(&2));
+        let backward: Vec<_> = other.iter_back().copied().collect();
+        assert_eq!(backward, vec![4, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`at` split index (is 4) should be <= len (is 3)")]
+    fn test_split_off_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_append_list() {
+        let mut a = DoublyLinkedList::from([1, 2]);
+        let mut b = DoublyLinkedList::from([3, 4, 5]);
+        a.append_list(&mut b);
+        assert_eq!(a, vec![1, 2, 3, 4, 5]);
+        assert_eq!(a.len(), 5);
+        assert!(b.is_empty());
+        assert_eq!(b.peek_front(), None);
+        let backward: Vec<_> = a.iter_back().copied().collect();
+        assert_eq!(backward, vec![5, 4, 3, 2, 1]);
+
+        b.append(6);
+        assert_eq!(b, vec![6]);
+
+        let mut empty = DoublyLinkedList::new();
+        empty.append_list(&mut a);
+        assert_eq!(empty, vec![1, 2, 3, 4, 5]);
+        assert_eq!(empty.peek_back(), Some(&5));
+        assert!(a.is_empty());
+
+        empty.append_list(&mut a);
+        assert_eq!(empty.len(), 5);
+    }
+
+    #[test]
+    fn test_prepend_list() {
+        let mut a = DoublyLinkedList::from([4, 5]);
+        let mut b = DoublyLinkedList::from([1, 2, 3]);
+        a.prepend_list(&mut b);
+        assert_eq!(a, vec![1, 2, 3, 4, 5]);
+        assert!(b.is_empty());
+        let backward: Vec<_> = a.iter_back().copied().collect();
+        assert_eq!(backward, vec![5, 4, 3, 2, 1]);
+
+        a.prepend_list(&mut b);
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.peek_front(), Some(&1));
+
+        let mut empty = DoublyLinkedList::new();
+        empty.prepend_list(&mut a);
+        assert_eq!(empty, vec![1, 2, 3, 4, 5]);
+        assert_eq!(empty.peek_front(), Some(&1));
+        assert_eq!(empty.peek_back(), Some(&5));
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut list: DoublyLinkedList<_> = (1..=5).collect();
+        list.rotate_left(2);
+        let mut expected: DoublyLinkedList<_> = (3..=5).collect();
+        expected.append(1);
+        expected.append(2);
+        assert_eq!(list, expected);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![2, 1, 5, 4, 3]);
+
+        list.rotate_left(0);
+        list.rotate_left(5);
+        assert_eq!(list, vec![3, 4, 5, 1, 2]);
+
+        list.rotate_left(8);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+
+        let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        empty.rotate_left(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let mut list: DoublyLinkedList<_> = (1..=5).collect();
+        list.rotate_right(2);
+        assert_eq!(list, vec![4, 5, 1, 2, 3]);
+        assert_eq!(list.peek_back(), Some(&3));
+
+        list.rotate_right(5);
+        assert_eq!(list, vec![4, 5, 1, 2, 3]);
+
+        list.rotate_right(7);
+        list.rotate_left(4);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+
+        let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        empty.rotate_right(1);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_forward() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.peek_next(), Some(&2));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.peek_prev(), Some(&1));
+        assert_eq!(cursor.peek_next(), Some(&3));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_next(), Some(&1));
+        assert_eq!(cursor.peek_prev(), Some(&3));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+    }
+
+    #[test]
+    fn test_cursor_backward() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        let copy = cursor.clone();
+        cursor.move_prev();
+        assert_eq!(copy.current(), Some(&3));
+        assert_eq!(cursor.current(), Some(&2));
+    }
+
+    #[test]
+    fn test_cursor_empty() {
+        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(list.cursor_back().index(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_navigation() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_prev();
+        assert_eq!(cursor.peek_prev(), Some(&1));
+        assert_eq!(cursor.peek_next(), Some(&3));
+        if let Some(value) = cursor.current_mut() {
+            *value = 20;
+        }
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.current_mut(), None);
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(list, vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert() {
+        let mut list = DoublyLinkedList::from([2, 4]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_before(1);
+        assert_eq!(cursor.current(), Some(&2));
+        assert_eq!(cursor.index(), Some(1));
+        cursor.insert_after(3);
+        assert_eq!(cursor.peek_next(), Some(&3));
+
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.insert_before(5);
+        cursor.insert_after(0);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.peek_next(), Some(&0));
+        assert_eq!(cursor.peek_prev(), Some(&5));
+
+        assert_eq!(list, vec![0, 1, 2, 3, 4, 5]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![5, 4, 3, 2, 1, 0]);
+
+        let mut empty = DoublyLinkedList::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.insert_after(2);
+        cursor.insert_after(1);
+        cursor.insert_before(3);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&1));
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(empty, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_remove_during_traversal() {
+        let mut list: DoublyLinkedList<_> = (0..8).collect();
+        let mut cursor = list.cursor_front_mut();
+        while let Some(&value) = cursor.current() {
+            if value % 3 == 0 {
+                assert_eq!(cursor.remove_current(), Some(value));
+            } else {
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.remove_current(), None);
+        assert_eq!(list, vec![1, 2, 4, 5, 7]);
+        assert_eq!(list.peek_back(), Some(&7));
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![7, 5, 4, 2, 1]);
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some(7));
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&5));
+        assert_eq!(cursor.index(), Some(3));
+    }
+
+    #[test]
+    fn test_cursor_mut_split_after() {
+        let mut list: DoublyLinkedList<_> = (0..5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        let tail = cursor.split_after();
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(tail, vec![2, 3, 4]);
+        assert_eq!(tail.len(), 3);
+        assert_eq!(list, vec![0, 1]);
+        assert_eq!(list.peek_back(), Some(&1));
+
+        let mut cursor = list.cursor_back_mut();
+        assert!(cursor.split_after().is_empty());
+        cursor.move_next();
+        let all = cursor.split_after();
+        assert_eq!(all, vec![0, 1]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_mut_splice() {
+        let mut list = DoublyLinkedList::from([3, 6]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.splice_before(&mut DoublyLinkedList::from([1, 2]));
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(2));
+
+        let mut middle = DoublyLinkedList::from([4, 5]);
+        cursor.splice_after(&mut middle);
+        assert!(middle.is_empty());
+        assert_eq!(cursor.peek_next(), Some(&4));
+
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.splice_after(&mut DoublyLinkedList::from([-1, 0]));
+        cursor.splice_before(&mut DoublyLinkedList::from([7]));
+        cursor.splice_before(&mut DoublyLinkedList::new());
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&7));
+        assert_eq!(cursor.index(), Some(8));
+
+        assert_eq!(list, vec![-1, 0, 1, 2, 3, 4, 5, 6, 7]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![7, 6, 5, 4, 3, 2, 1, 0, -1]);
+
+        let mut empty = DoublyLinkedList::new();
+        let mut cursor = empty.cursor_back_mut();
+        cursor.splice_before(&mut DoublyLinkedList::from([1, 2]));
+        assert_eq!(empty, vec![1, 2]);
+        assert_eq!(empty.peek_back(), Some(&2));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4, 5]);
+        list.swap(0, 4);
+        assert_eq!(list, vec![5, 2, 3, 4, 1]);
+        list.swap(1, 2);
+        assert_eq!(list, vec![5, 3, 2, 4, 1]);
+        list.swap(3, 3);
+        assert_eq!(list, vec![5, 3, 2, 4, 1]);
+        list.swap(3, 0);
+        assert_eq!(list, vec![4, 3, 2, 5, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 2 but the index is 2")]
+    fn test_swap_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2]);
+        list.swap(0, 2);
+    }
+
+    #[test]
+    fn test_swap_adjacent() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4]);
+        list.swap_adjacent(0);
+        assert_eq!(list, vec![2, 1, 3, 4]);
+        list.swap_adjacent(1);
+        assert_eq!(list, vec![2, 3, 1, 4]);
+        list.swap_adjacent(2);
+        assert_eq!(list, vec![2, 3, 4, 1]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![1, 4, 3, 2]);
+        assert_eq!(list.peek_front(), Some(&2));
+        assert_eq!(list.peek_back(), Some(&1));
+
+        let mut pair = DoublyLinkedList::from([1, 2]);
+        pair.swap_adjacent(0);
+        assert_eq!(pair, vec![2, 1]);
+        assert_eq!(pair.iter_back().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent swap index (is 2) should be < len - 1 (len is 3)")]
+    fn test_swap_adjacent_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.swap_adjacent(2);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list: DoublyLinkedList<_> = (0..10).collect();
+        list.truncate(10);
+        assert_eq!(list.len(), 10);
+        list.truncate(12);
+        assert_eq!(list.len(), 10);
+
+        list.truncate(1);
+        assert_eq!(list, vec![0]);
+        assert_eq!(list.peek_back(), Some(&0));
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_front(), None);
+        list.append(1);
+        assert_eq!(list, vec![1]);
+
+        let mut list: DoublyLinkedList<_> = (0..10).collect();
+        list.truncate(7);
+        assert_eq!(list, (0..7).collect::<Vec<_>>());
+        assert_eq!(list.iter_back().next(), Some(&6));
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut list = DoublyLinkedList::new();
+        list.resize(3, String::from("x"));
+        assert_eq!(
+            list.iter().map(String::as_str).collect::<Vec<_>>(),
+            vec!["x", "x", "x"]
+        );
+
+        list.resize(5, String::from("y"));
+        assert_eq!(
+            list.iter().map(String::as_str).collect::<Vec<_>>(),
+            vec!["x", "x", "x", "y", "y"]
+        );
+
+        list.resize(5, String::from("z"));
+        assert_eq!(list.len(), 5);
+
+        list.resize(2, String::from("z"));
+        assert_eq!(
+            list.iter().map(String::as_str).collect::<Vec<_>>(),
+            vec!["x", "x"]
+        );
+        assert_eq!(list.peek_back().map(String::as_str), Some("x"));
+    }
+
+    #[test]
+    fn test_map() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let mapped = list.map(|x| format!("#{}", x));
+        assert_eq!(mapped.len(), 3);
+        assert_eq!(format!("{}", mapped), "[#1, #2, #3]");
+
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let mapped = empty.map(|x| x.to_string());
+        assert!(mapped.is_empty());
+    }
+
+    #[test]
+    fn test_filter() {
+        let list: DoublyLinkedList<_> = (0..6).collect();
+        let evens = list.filter(|&x| x % 2 == 0);
+        assert_eq!(evens, vec![0, 2, 4]);
+
+        let all = evens.filter(|_| true);
+        assert_eq!(all, vec![0, 2, 4]);
+
+        let none = all.filter(|_| false);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_partition() {
+        let list: DoublyLinkedList<_> = (0..6).collect();
+        let (evens, odds) = list.partition(|&x| x % 2 == 0);
+        assert_eq!(evens, vec![0, 2, 4]);
+        assert_eq!(odds, vec![1, 3, 5]);
+
+        let (all, none) = evens.partition(|_| true);
+        assert_eq!(all, vec![0, 2, 4]);
+        assert!(none.is_empty());
+
+        let (none, all) = odds.partition(|_| false);
+        assert!(none.is_empty());
+        assert_eq!(all, vec![1, 3, 5]);
+        assert_eq!(all.peek_back(), Some(&5));
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4, 5, 6, 8, 9]);
+        let evens: Vec<_> = list.extract_if(|x| *x % 2 == 0).collect();
+        assert_eq!(evens, vec![2, 4, 6, 8]);
+        assert_eq!(list, vec![1, 3, 5, 9]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![9, 5, 3, 1]);
+
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        assert_eq!(list.extract_if(|x| *x % 2 == 1).next(), Some(1));
+        assert_eq!(list, vec![0, 2, 3, 4, 5]);
+
+        let mut iter = list.extract_if(|_| true);
+        for _ in 0..5 {
+            assert!(iter.next().is_some());
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_zip() {
+        let a = DoublyLinkedList::from([1, 2, 3]);
+        let b = DoublyLinkedList::from(['a', 'b', 'c']);
+        assert_eq!(a.zip(b), vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+
+        let short = DoublyLinkedList::from([1, 2]);
+        let long = DoublyLinkedList::from(["x", "y", "z", "w"]);
+        let zipped = short.zip(long);
+        assert_eq!(zipped, vec![(1, "x"), (2, "y")]);
+        assert_eq!(zipped.len(), 2);
+
+        let a: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let b: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert!(a.zip(b).is_empty());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let nested = DoublyLinkedList::from([
+            DoublyLinkedList::new(),
+            DoublyLinkedList::from([1, 2]),
+            DoublyLinkedList::new(),
+            DoublyLinkedList::from([3]),
+            DoublyLinkedList::from([4, 5]),
+            DoublyLinkedList::new(),
+        ]);
+        let flat = nested.flatten();
+        assert_eq!(flat, vec![1, 2, 3, 4, 5]);
+        let backward: Vec<_> = flat.iter_back().copied().collect();
+        assert_eq!(backward, vec![5, 4, 3, 2, 1]);
+
+        let single = DoublyLinkedList::from([DoublyLinkedList::from([7, 8])]);
+        assert_eq!(single.flatten(), vec![7, 8]);
+
+        let empty: DoublyLinkedList<DoublyLinkedList<i32>> = DoublyLinkedList::new();
+        assert!(empty.flatten().is_empty());
+    }
+
+    #[test]
+    fn test_flat_map() {
+        let list = DoublyLinkedList::from([4, 6]);
+        let divisors = list.flat_map(|n| (1..=n).filter(|d| n % d == 0).collect());
+        assert_eq!(divisors, vec![1, 2, 4, 1, 2, 3, 6]);
+
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let mapped = list.flat_map(|n| {
+            if n == 2 {
+                DoublyLinkedList::new()
+            } else {
+                DoublyLinkedList::from([n, n])
+            }
+        });
+        assert_eq!(mapped, vec![1, 1, 3, 3]);
+
+        let list = DoublyLinkedList::from([1, 2]);
+        assert!(list.flat_map(|_| DoublyLinkedList::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let a = DoublyLinkedList::from([1, 3, 5, 7]);
+        let b = DoublyLinkedList::from([2, 4, 6]);
+        let merged = a.merge_sorted(b);
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(merged.peek_back(), Some(&7));
+
+        let low = DoublyLinkedList::from([1, 2]);
+        let high = DoublyLinkedList::from([8, 9]);
+        assert_eq!(high.merge_sorted(low), vec![1, 2, 8, 9]);
+
+        let empty = DoublyLinkedList::new();
+        assert_eq!(empty.merge_sorted(DoublyLinkedList::from([1])), vec![1]);
+    }
+
+    #[test]
+    fn test_merge_sorted_by_stable() {
+        let a = DoublyLinkedList::from([(1, 'a'), (2, 'a')]);
+        let b = DoublyLinkedList::from([(1, 'b'), (2, 'b'), (3, 'b')]);
+        let merged = a.merge_sorted_by(b, |x, y| x.0.cmp(&y.0));
+        assert_eq!(
+            merged,
+            vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (3, 'b')]
+        );
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list = DoublyLinkedList::new();
+        list.insert_sorted(5);
+        assert_eq!(list, vec![5]);
+        list.insert_sorted(1);
+        list.insert_sorted(9);
+        list.insert_sorted(5);
+        list.insert_sorted(3);
+        assert_eq!(list, vec![1, 3, 5, 5, 9]);
+        let backward: Vec<_> = list.iter_back().copied().collect();
+        assert_eq!(backward, vec![9, 5, 5, 3, 1]);
+    }
+
+    #[test]
+    fn test_insert_sorted_by() {
+        let mut list = DoublyLinkedList::from([(1, 'a'), (2, 'a')]);
+        list.insert_sorted_by((1, 'b'), |x, y| x.0.cmp(&y.0));
+        assert_eq!(list, vec![(1, 'a'), (1, 'b'), (2, 'a')]);
+
+        let mut words = DoublyLinkedList::from(["a", "ccc"]);
+        words.insert_sorted_by_key("bb", |s| s.len());
+        words.insert_sorted_by_key("dddd", |s| s.len());
+        words.insert_sorted_by_key("", |s| s.len());
+        assert_eq!(words, vec!["", "a", "bb", "ccc", "dddd"]);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let list = DoublyLinkedList::from([3, 1, 4, 1, 5]);
+        assert_eq!(list.min_element(), Some(&1));
+        assert_eq!(list.max_element(), Some(&5));
+
+        let words = DoublyLinkedList::from(["pear", "apple", "fig"]);
+        assert_eq!(words.min_element(), Some(&"apple"));
+        assert_eq!(words.max_element(), Some(&"pear"));
+
+        let single = DoublyLinkedList::from([7]);
+        assert_eq!(single.min_element(), Some(&7));
+        assert_eq!(single.max_element(), Some(&7));
+
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.min_element(), None);
+        assert_eq!(empty.max_element(), None);
+    }
+
+    #[test]
+    fn test_min_max_by_key() {
+        let words = DoublyLinkedList::from(["bb", "a", "cc", "d"]);
+        assert_eq!(words.min_by_key(|s| s.len()), Some(&"a"));
+        assert_eq!(words.max_by_key(|s| s.len()), Some(&"cc"));
+
+        let empty: DoublyLinkedList<&str> = DoublyLinkedList::new();
+        assert_eq!(empty.min_by_key(|s| s.len()), None);
+        assert_eq!(empty.max_by_key(|s| s.len()), None);
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let list = DoublyLinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.clone().sum::<i32>(), 10);
+        assert_eq!(list.product::<i32>(), 24);
+
+        let floats = DoublyLinkedList::from([0.5, 1.5, 2.0]);
+        assert_eq!(floats.clone().sum::<f64>(), 4.0);
+        assert_eq!(floats.product::<f64>(), 1.5);
+
+        let empty: DoublyLinkedList<i64> = DoublyLinkedList::new();
+        assert_eq!(empty.clone().sum::<i64>(), 0);
+        assert_eq!(empty.product::<i64>(), 1);
+    }
+
+    #[test]
+    fn test_send_sync() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let list: DoublyLinkedList<i32> = (1..=4).collect();
+        let handle = thread::spawn(move || list.sum::<i32>());
+        assert_eq!(handle.join().unwrap(), 10);
+
+        let shared = Arc::new(DoublyLinkedList::from([1, 2, 3]));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || shared.len())
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 3);
+        }
+
+        fn assert_send<T: Send>(_: &T) {}
+        fn assert_sync<T: Sync>(_: &T) {}
+
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        let iter = list.iter();
+        assert_send(&iter);
+        assert_sync(&iter);
+        let iter_back = list.iter_back();
+        assert_send(&iter_back);
+        assert_sync(&iter_back);
+        let windows = list.windows(2);
+        assert_send(&windows);
+        assert_sync(&windows);
+        let chunks = list.chunks(2);
+        assert_send(&chunks);
+        assert_sync(&chunks);
+        let cursor = list.cursor_front();
+        assert_send(&cursor);
+        assert_sync(&cursor);
+        let iter_mut = list.iter_mut();
+        assert_send(&iter_mut);
+        assert_sync(&iter_mut);
+        let iter_back_mut = list.iter_back_mut();
+        assert_send(&iter_back_mut);
+        assert_sync(&iter_back_mut);
+        let cursor_mut = list.cursor_front_mut();
+        assert_send(&cursor_mut);
+        let extract_if = list.extract_if(|x| *x > 1);
+        assert_send(&extract_if);
+
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        let iter_mut = list.iter_mut();
+        thread::scope(|scope| {
+            scope.spawn(move || iter_mut.for_each(|x| *x *= 10));
+        });
+        let iter = list.iter();
+        let sum = thread::scope(|scope| scope.spawn(move || iter.sum::<i32>()).join().unwrap());
+        assert_eq!(sum, 60);
+    }
+
+    #[test]
+    fn test_pop_if() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        assert_eq!(list.pop_if_front(|&x| x > 1), None);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_if_front(|&x| x == 1), Some(1));
+        assert_eq!(list, vec![2, 3]);
+
+        assert_eq!(list.pop_if_back(|&x| x < 3), None);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop_if_back(|&x| x == 3), Some(3));
+        assert_eq!(list, vec![2]);
+
+        list.clear();
+        assert_eq!(list.pop_if_front(|_| true), None);
+        assert_eq!(list.pop_if_back(|_| true), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_consistency() {
+        let mut list: DoublyLinkedList<_> = (0..5).collect();
+        list.assert_consistent();
+        list.reverse();
+        list.sort();
+        list.rotate_left(2);
+        list.drain(1..3);
+        list.assert_consistent();
+        DoublyLinkedList::<i32>::new().assert_consistent();
+
+        list.len += 1;
+        assert!(!list.is_consistent());
+        list.len -= 1;
+
+        unsafe {
+            let tail = list.tail;
+            (*tail).next = list.head;
+            assert!(!list.is_consistent());
+            (*tail).next = ptr::null_mut();
+
+            let second = (*list.head).next;
+            (*second).prev = ptr::null_mut();
+            assert!(!list.is_consistent());
+            (*second).prev = list.head;
+        }
+        assert!(list.is_consistent());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "doubly-linked list is inconsistent")]
+    fn test_assert_consistent_panics() {
+        let mut list = DoublyLinkedList::from([1, 2]);
+        list.len = 3;
+        list.assert_consistent();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        let back: DoublyLinkedList<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, list);
+
+        let words = DoublyLinkedList::from([String::from("a"), String::from("b")]);
+        let json = serde_json::to_string(&words).unwrap();
+        assert_eq!(json, r#"["a","b"]"#);
+        let back: DoublyLinkedList<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, words);
+
+        let empty: DoublyLinkedList<String> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+
+        assert!(serde_json::from_str::<DoublyLinkedList<i32>>("{}").is_err());
+    }
+
+    #[test]
+    fn test_repeat() {
+        let list = DoublyLinkedList::repeat(7, 0);
+        assert!(list.is_empty());
+
+        let list = DoublyLinkedList::repeat(String::from("a"), 1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.peek_front().map(String::as_str), Some("a"));
+
+        let list = DoublyLinkedList::repeat(7, 12);
+        assert_eq!(list.len(), 12);
+        assert_eq!(list.iter().count(), 12);
+        assert!(list.iter().all(|&x| x == 7));
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let squares = DoublyLinkedList::from_fn(5, |i| i * i);
+        assert_eq!(squares, vec![0, 1, 4, 9, 16]);
+
+        let mut calls = Vec::new();
+        let list = DoublyLinkedList::from_fn(3, |i| {
+            calls.push(i);
+            i
+        });
+        assert_eq!(calls, vec![0, 1, 2]);
+        assert_eq!(list.len(), 3);
+
+        let empty = DoublyLinkedList::from_fn(0, |_| -> i32 { unreachable!() });
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_append_prepend_iter() {
+        let mut list = DoublyLinkedList::from([3]);
+        list.append_iter(std::iter::empty());
+        assert_eq!(list, vec![3]);
+
+        list.append_iter(vec![4, 5]);
+        assert_eq!(list, vec![3, 4, 5]);
+
+        list.prepend_iter(1..3);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+
+        list.prepend_iter((-2..1).rev());
+        assert_eq!(list, vec![0, -1, -2, 1, 2, 3, 4, 5]);
+        assert_eq!(list.peek_front(), Some(&0));
+    }
+
+    #[test]
+    fn test_into_to_vec() {
+        let list = DoublyLinkedList::from([3, 1, 2]);
+        let copy = list.to_vec();
+        assert_eq!(copy, vec![3, 1, 2]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list, copy);
+
+        assert_eq!(list.into_vec(), vec![3, 1, 2]);
+        assert!(DoublyLinkedList::<i32>::new().into_vec().is_empty());
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4]);
+        for index in 0..4 {
+            let old = list.replace(index, (index as i32 + 1) * 10);
+            assert_eq!(old, Some(index as i32 + 1));
+            assert_eq!(list[index], (index as i32 + 1) * 10);
+        }
+        assert_eq!(list, vec![10, 20, 30, 40]);
+        assert_eq!(list.replace(4, 50), None);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn test_map_in_place() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.map_in_place(|x| *x *= 2);
+        assert_eq!(list, vec![2, 4, 6]);
+
+        let mut empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        empty.map_in_place(|_| unreachable!());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_fold() {
+        let list = DoublyLinkedList::from([1, 2, 3, 4]);
+        assert_eq!(list.clone().fold(0, |acc, x| acc + x), 10);
+
+        let words = DoublyLinkedList::from(["a", "b", "c"]);
+        assert_eq!(words.clone().fold(String::new(), |acc, s| acc + s), "abc");
+        assert_eq!(words.fold_right(String::new(), |acc, s| acc + s), "cba");
+
+        let matches = list.fold_right(0, |count, x| if x % 2 == 0 { count + 1 } else { count });
+        assert_eq!(matches, 2);
+    }
+
+    #[test]
+    fn test_all_any() {
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert!(empty.all(|_| false));
+        assert!(!empty.any(|_| true));
+
+        let list = DoublyLinkedList::from([2, 4, 5, 6]);
+        assert!(list.all(|&x| x > 0));
+        assert!(!list.all(|&x| x % 2 == 0));
+        assert!(!list.any(|&x| x > 9));
+
+        let mut visited = 0;
+        assert!(list.any(|&x| {
+            visited += 1;
+            x == 4
+        }));
+        assert_eq!(visited, 2);
+
+        let mut visited = 0;
+        assert!(!list.all(|&x| {
+            visited += 1;
+            x != 5
+        }));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn test_count_where() {
+        let list: DoublyLinkedList<_> = (0..10).collect();
+        assert_eq!(list.count_where(|&x| x > 99), 0);
+        assert_eq!(list.count_where(|&x| x >= 0), 10);
+        assert_eq!(list.count_where(|&x| x % 2 == 0), 5);
+        assert_eq!(list.count_where(|_| true), list.len());
+    }
+
+    #[test]
+    fn test_reduce() {
+        let list = DoublyLinkedList::from([3, 9, 2, 7]);
+        assert_eq!(list.reduce(|a, b| a.max(b)), Some(9));
+
+        let words =
+            DoublyLinkedList::from([String::from("a"), String::from("b"), String::from("c")]);
+        assert_eq!(words.reduce(|a, b| a + &b), Some(String::from("abc")));
+
+        let single = DoublyLinkedList::from([5]);
+        assert_eq!(single.reduce(|_, _| unreachable!()), Some(5));
+
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.reduce(|a, b| a + b), None);
+    }
+
+    #[test]
+    fn test_truncate_front() {
+        let mut list: DoublyLinkedList<_> = (0..6).collect();
+        list.truncate_front(0);
+        assert_eq!(list.len(), 6);
+
+        list.truncate_front(2);
+        assert_eq!(list, vec![2, 3, 4, 5]);
+        assert_eq!(list.iter_back().last(), Some(&2));
+
+        list.truncate(3);
+        list.truncate_front(1);
+        assert_eq!(list, vec![3, 4]);
+
+        list.truncate_front(10);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+    }
+
+    #[test]
+    fn test_take_front_back() {
+        let mut list: DoublyLinkedList<_> = (0..8).collect();
+        let front = list.take_front(3);
+        let back = list.take_back(2);
+        assert_eq!(front, vec![0, 1, 2]);
+        assert_eq!(back, vec![6, 7]);
+        assert_eq!(list, vec![3, 4, 5]);
+        assert_eq!(front.len() + list.len() + back.len(), 8);
+
+        assert!(list.take_front(0).is_empty());
+        assert!(list.take_back(0).is_empty());
+
+        let all = list.take_back(10);
+        assert_eq!(all, vec![3, 4, 5]);
+        assert!(list.is_empty());
+        assert!(list.take_front(1).is_empty());
+    }
+
+    #[test]
+    fn test_nth() {
+        let mut list = DoublyLinkedList::from([10, 20, 30, 40]);
+        for n in 0..4 {
+            assert_eq!(list.nth(n), list.iter().nth(n));
+            assert_eq!(list.nth_back(n), list.iter().rev().nth(n));
+        }
+        assert_eq!(list.nth(4), None);
+        assert_eq!(list.nth_back(4), None);
+
+        *list.nth_mut(0).unwrap() += 1;
+        *list.nth_back_mut(0).unwrap() += 2;
+        *list.nth_back_mut(2).unwrap() += 3;
+        assert_eq!(list.nth_mut(4), None);
+        assert_eq!(list.nth_back_mut(4), None);
+        assert_eq!(list, vec![11, 23, 30, 42]);
+    }
+
+    #[test]
+    fn test_front_to_back() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.front_to_back();
+        assert_eq!(list, vec![2, 3, 1]);
+        assert_eq!(list.iter_back().copied().collect::<Vec<_>>(), vec![1, 3, 2]);
+
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.back_to_front();
+        assert_eq!(list, vec![3, 1, 2]);
+        assert_eq!(list.iter_back().copied().collect::<Vec<_>>(), vec![2, 1, 3]);
+
+        list.front_to_back();
+        assert_eq!(list, vec![1, 2, 3]);
+
+        let mut pair = DoublyLinkedList::from([1, 2]);
+        pair.front_to_back();
+        assert_eq!(pair, vec![2, 1]);
+        assert_eq!(pair.peek_back(), Some(&1));
+
+        let mut single = DoublyLinkedList::from([1]);
+        single.front_to_back();
+        single.back_to_front();
+        assert_eq!(single, vec![1]);
+    }
+
+    #[test]
+    fn test_add() {
+        let a = DoublyLinkedList::from([1, 2]);
+        let b = DoublyLinkedList::from([3, 4]);
+        let c = a + b;
+        assert_eq!(c, vec![1, 2, 3, 4]);
+        assert_eq!(c.len(), 4);
+
+        let mut d = c + DoublyLinkedList::new();
+        d += DoublyLinkedList::from([5]);
+        d += DoublyLinkedList::new();
+        assert_eq!(d, vec![1, 2, 3, 4, 5]);
+        assert_eq!(d.peek_back(), Some(&5));
+    }
+
+    #[test]
+    fn test_windows() {
+        let list: DoublyLinkedList<_> = (1..=5).collect();
+
+        let windows: Vec<_> = list.windows(1).collect();
+        assert_eq!(
+            windows,
+            vec![vec![&1], vec![&2], vec![&3], vec![&4], vec![&5]]
+        );
+
+        let windows: Vec<_> = list.windows(2).collect();
+        assert_eq!(
+            windows,
+            vec![vec![&1, &2], vec![&2, &3], vec![&3, &4], vec![&4, &5]]
+        );
+
+        let mut windows = list.windows(3);
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows.next(), Some(vec![&1, &2, &3]));
+        assert_eq!(windows.next(), Some(vec![&2, &3, &4]));
+        assert_eq!(windows.next(), Some(vec![&3, &4, &5]));
+        assert_eq!(windows.next(), None);
+
+        let windows: Vec<_> = list.windows(5).collect();
+        assert_eq!(windows, vec![vec![&1, &2, &3, &4, &5]]);
+
+        assert_eq!(list.windows(6).next(), None);
+        assert_eq!(list.windows(6).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_zero() {
+        let list = DoublyLinkedList::from([1]);
+        list.windows(0);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let list: DoublyLinkedList<_> = (1..=7).collect();
+
+        let chunks: Vec<_> = list.chunks(3).collect();
+        assert_eq!(chunks, vec![vec![&1, &2, &3], vec![&4, &5, &6], vec![&7]]);
+
+        let mut iter = list.chunks(2);
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        iter.next();
+        assert_eq!(iter.len(), 3);
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(list.chunks(7).len(), 1);
+
+        let chunks: Vec<_> = list.chunks(10).collect();
+        assert_eq!(chunks, vec![list.iter().collect::<Vec<_>>()]);
+
+        let empty: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(empty.chunks(2).next(), None);
+        assert_eq!(empty.chunks(2).size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero() {
+        let list = DoublyLinkedList::from([1]);
+        list.chunks(0);
+    }
+
+    #[test]
+    fn test_interleave() {
+        let a = DoublyLinkedList::from([1, 3, 5]);
+        let b = DoublyLinkedList::from([2, 4, 6]);
+        assert_eq!(a.interleave(b), vec![1, 2, 3, 4, 5, 6]);
+
+        let long = DoublyLinkedList::from([1, 3, 5, 7]);
+        let short = DoublyLinkedList::from([2]);
+        assert_eq!(long.interleave(short), vec![1, 2, 3, 5, 7]);
+
+        let short = DoublyLinkedList::from([1]);
+        let long = DoublyLinkedList::from([2, 4, 6]);
+        let merged = short.interleave(long);
+        assert_eq!(merged, vec![1, 2, 4, 6]);
+        assert_eq!(merged.peek_back(), Some(&6));
+
+        let a = DoublyLinkedList::from([1, 2]);
+        assert_eq!(a.interleave(DoublyLinkedList::new()), vec![1, 2]);
+        let b = DoublyLinkedList::from([1, 2]);
+        assert_eq!(DoublyLinkedList::new().interleave(b), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_move_element() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4, 5]);
+        list.move_element(0, 4);
+        assert_eq!(list, vec![2, 3, 4, 5, 1]);
+        list.move_element(4, 0);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+        list.move_element(1, 3);
+        assert_eq!(list, vec![1, 3, 4, 2, 5]);
+        list.move_element(3, 1);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+        list.move_element(2, 2);
+        assert_eq!(list, vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.peek_front(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&5));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_move_element_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.move_element(0, 3);
+    }
+
+    #[test]
+    fn test_push_pop_is_lifo() {
+        let mut stack = DoublyLinkedList::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.pop(), Some(3));
+        stack.push(4);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_enqueue_dequeue_is_fifo() {
+        let mut queue = DoublyLinkedList::new();
+        queue.enqueue(1);
+        queue.enqueue(2);
+        queue.enqueue(3);
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(4);
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None);
+
+        queue.enqueue(5);
+        queue.push(6);
+        queue.enqueue(7);
+        assert_eq!(queue.pop(), Some(7));
+        assert_eq!(queue.dequeue(), Some(5));
+        assert_eq!(queue, vec![6]);
+    }
+
+    #[test]
+    fn test_partial_eq_with_vec_and_slice_both_ways() {
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let equal = vec![1, 2, 3];
+        let unequal = vec![1, 2, 4];
+
+        assert_eq!(list, equal);
+        assert_eq!(equal, list);
+        assert_ne!(list, unequal);
+        assert_ne!(unequal, list);
+
+        assert_eq!(list, equal.as_slice());
+        assert_eq!(equal.as_slice(), list);
+        assert_ne!(list, unequal.as_slice());
+        assert_ne!(unequal.as_slice(), list);
+
+        assert!(list == *equal.as_slice());
+        assert!(*equal.as_slice() == list);
+        assert!(*unequal.as_slice() != list);
+        assert!(equal[..2] != list);
+    }
+
+    #[test]
+    fn test_get_unchecked_matches_get() {
+        let mut list = DoublyLinkedList::from([10, 20, 30, 40, 50]);
+        for i in 0..list.len() + 1 {
+            if i < list.len() {
+                let value = unsafe { list.get_unchecked(i) };
+                assert_eq!(Some(value), list.get(i));
+            } else {
+                assert_eq!(list.get(i), None);
+            }
+        }
+        for i in 0..list.len() {
+            unsafe { *list.get_unchecked_mut(i) += 1 };
+        }
+        assert_eq!(list, vec![11, 21, 31, 41, 51]);
+    }
+
+    #[test]
+    fn test_into_sorted_matches_sort() {
+        let values = [5, 3, 8, 1, 9, 2, 7];
+        let mut sorted = DoublyLinkedList::from(values);
+        sorted.sort();
+        assert_eq!(DoublyLinkedList::from(values).into_sorted(), sorted);
+
+        let descending = DoublyLinkedList::from(values).into_sorted_by(|a, b| b.cmp(a));
+        assert_eq!(descending, vec![9, 8, 7, 5, 3, 2, 1]);
+
+        let words = DoublyLinkedList::from(["ccc", "a", "bb", "dd"]);
+        assert_eq!(
+            words.into_sorted_by_key(|s| s.len()),
+            vec!["a", "bb", "dd", "ccc"]
+        );
+        assert!(DoublyLinkedList::<i32>::new().into_sorted().is_empty());
+    }
+
+    #[test]
+    fn test_difference_and_intersect() {
+        let list = DoublyLinkedList::from([1, 2, 3, 2, 4]);
+        let empty = DoublyLinkedList::new();
+        assert_eq!(list.difference(&empty), list);
+        assert!(list.intersect(&empty).is_empty());
+        assert!(empty.difference(&list).is_empty());
+
+        let disjoint = DoublyLinkedList::from([7, 8]);
+        assert_eq!(list.difference(&disjoint), list);
+        assert!(list.intersect(&disjoint).is_empty());
+
+        assert!(list.difference(&list).is_empty());
+        assert_eq!(list.intersect(&list), list);
+
+        let overlap = DoublyLinkedList::from([4, 2, 9]);
+        assert_eq!(list.difference(&overlap), vec![1, 3]);
+        assert_eq!(list.intersect(&overlap), vec![2, 2, 4]);
+    }
+
+    #[test]
+    fn test_io_write_appends_bytes() {
+        use std::io::Write;
+
+        let mut buf = DoublyLinkedList::new();
+        write!(buf, "len={}", 3).unwrap();
+        writeln!(buf, ";ok").unwrap();
+        buf.flush().unwrap();
+        buf.prepend(b'>');
+        assert_eq!(buf.into_vec(), b">len=3;ok\n".to_vec());
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let list = DoublyLinkedList::from([1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            list.iter_from(2).copied().collect::<Vec<_>>(),
+            vec![3, 4, 5, 6]
+        );
+        assert_eq!(list.iter_from(4).copied().collect::<Vec<_>>(), vec![5, 6]);
+        assert_eq!(list.iter_from(0).len(), 6);
+
+        let mut iter = list.iter_from(3);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(list.iter_from(6).next(), None);
+        assert_eq!(list.iter_from(100).len(), 0);
+        assert_eq!(DoublyLinkedList::<i32>::new().iter_from(0).next(), None);
+    }
+
+    #[test]
+    fn test_iter_back_from() {
+        let list = DoublyLinkedList::from([1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            list.iter_back_from(2).copied().collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(
+            list.iter_back_from(4).copied().collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+        assert_eq!(list.iter_back_from(0).copied().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(list.iter_back_from(5).count(), 6);
+        assert_eq!(list.iter_back_from(6).next(), None);
+    }
+
+    #[test]
+    fn test_first_last_aliases() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        assert_eq!(list.first(), list.peek_front());
+        assert_eq!(list.last(), list.peek_back());
+        *list.first_mut().unwrap() = 10;
+        *list.last_mut().unwrap() = 30;
+        assert_eq!(list, vec![10, 2, 30]);
+
+        let mut empty = DoublyLinkedList::<i32>::new();
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+        assert_eq!(empty.first_mut(), None);
+        assert_eq!(empty.last_mut(), None);
+    }
+
+    #[test]
+    fn test_into_array() {
+        let list = DoublyLinkedList::from([String::from("a"), String::from("b")]);
+        let array: [String; 2] = list.into_array().unwrap();
+        assert_eq!(array, [String::from("a"), String::from("b")]);
+
+        let list = DoublyLinkedList::from([1, 2, 3]);
+        let list = list.into_array::<2>().unwrap_err();
+        assert_eq!(list, vec![1, 2, 3]);
+        let list = list.into_array::<4>().unwrap_err();
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list.into_array::<3>().unwrap(), [1, 2, 3]);
+
+        let empty: [i32; 0] = DoublyLinkedList::new().into_array().unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "arbitrary", debug_assertions))]
+    fn test_arbitrary_list_is_consistent() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: Vec<u8> = (0..=255).cycle().take(1024).collect();
+        for start in [0, 1, 17, 500, 1023] {
+            let mut u = Unstructured::new(&bytes[start..]);
+            let list = DoublyLinkedList::<u16>::arbitrary(&mut u).unwrap();
+            list.assert_consistent();
+            assert_eq!(list.len(), list.iter().count());
+        }
+
+        let mut u = Unstructured::new(&[]);
+        assert!(DoublyLinkedList::<u8>::arbitrary(&mut u)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_rayon_parallel_iter() {
+        use rayon::prelude::*;
+
+        let list: DoublyLinkedList<u64> = (1..=10_000).collect();
+        let squares: Vec<u64> = list.par_iter().map(|x| x * x).collect();
+        assert_eq!(squares.len(), 10_000);
+        assert_eq!(squares[..3], [1, 4, 9]);
+        assert_eq!(list.par_iter().sum::<u64>(), 50_005_000);
+
+        let max = list.into_par_iter().reduce(|| 0, u64::max);
+        assert_eq!(max, 10_000);
+        assert_eq!(DoublyLinkedList::<u64>::new().into_par_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut list = DoublyLinkedList::from([String::from("a")]);
+        list.extend_from_slice(&[]);
+        assert_eq!(list.len(), 1);
+        list.extend_from_slice(&[String::from("b")]);
+        assert_eq!(
+            list,
+            vec!["a", "b"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        );
+
+        let mut numbers = DoublyLinkedList::from([1, 2]);
+        numbers.extend_from_slice(&[3, 4, 5, 6]);
+        assert_eq!(numbers, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(numbers.peek_back(), Some(&6));
+    }
+
+    #[test]
+    fn test_to_string_list() {
+        let numbers = DoublyLinkedList::from([1, -2, 30]);
+        assert_eq!(numbers.to_string_list().into_vec(), ["1", "-2", "30"]);
+
+        let words = DoublyLinkedList::from(["a", "bc"]);
+        assert_eq!(words.to_string_list().into_vec(), ["a", "bc"]);
+
+        struct Point(i32, i32);
+        impl fmt::Display for Point {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "({}, {})", self.0, self.1)
+            }
+        }
+        let points = DoublyLinkedList::from([Point(1, 2), Point(3, 4)]);
+        assert_eq!(points.to_string_list().into_vec(), ["(1, 2)", "(3, 4)"]);
+        assert!(DoublyLinkedList::<i32>::new().to_string_list().is_empty());
+    }
+
+    #[test]
+    fn test_splice_range() {
+        let mut list = DoublyLinkedList::from([1, 2, 3, 4, 5]);
+        list.splice_range(1..3, [20, 30, 40]);
+        assert_eq!(list, vec![1, 20, 30, 40, 4, 5]);
+        assert_eq!(list.len(), 6);
+
+        list.splice_range(..2, [0]);
+        assert_eq!(list, vec![0, 30, 40, 4, 5]);
+        list.splice_range(3.., [9, 9]);
+        assert_eq!(list, vec![0, 30, 40, 9, 9]);
+        assert_eq!(list.peek_back(), Some(&9));
+        list.splice_range(2..2, [7]);
+        assert_eq!(list, vec![0, 30, 7, 40, 9, 9]);
+        list.splice_range(.., []);
+        assert!(list.is_empty());
+        list.splice_range(.., [1, 2]);
+        assert_eq!(list, vec![1, 2]);
+        assert_eq!(list.peek_front(), Some(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 4 out of range for list of length 3")]
+    fn test_splice_range_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.splice_range(1..4, [0]);
+    }
+
+    #[test]
+    fn test_cursor_index_tracks_moves() {
+        let list = DoublyLinkedList::from([10, 20, 30, 40]);
+        let mut cursor = list.cursor_front();
+        for expected in 0..4 {
+            assert_eq!(cursor.index(), Some(expected));
+            cursor.move_next();
+        }
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(3));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(cursor.current(), Some(&20));
+
+        let mut cursor = list.cursor_back();
+        assert_eq!(cursor.index(), Some(3));
+        for _ in 0..4 {
+            cursor.move_prev();
+        }
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+
+        assert_eq!(DoublyLinkedList::<i32>::new().cursor_front().index(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_index_after_edits() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(1));
+        cursor.insert_before(0);
+        assert_eq!(cursor.index(), Some(2));
+        cursor.insert_after(9);
+        assert_eq!(cursor.index(), Some(2));
+        cursor.remove_current();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&9));
+        assert_eq!(list, vec![1, 0, 9, 3]);
+    }
+
+    #[test]
+    fn test_cursor_mut_move_to_index() {
+        let mut list = DoublyLinkedList::from([0, 1, 2, 3, 4]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_to_index(3);
+        assert_eq!(cursor.current(), Some(&3));
+        cursor.insert_before(10);
+        assert_eq!(cursor.index(), Some(4));
+        cursor.move_to_index(0);
+        assert_eq!(cursor.current(), Some(&0));
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+
+        cursor.move_to_index(6);
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&4));
+
+        cursor.move_to_index(1);
+        let tail = cursor.split_after();
+        assert_eq!(tail, vec![2, 10, 3, 4]);
+        cursor.move_to_index(0);
+        assert_eq!(cursor.current(), Some(&0));
+        cursor.move_to_index(2);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(list, vec![0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cursor index (is 4) should be <= len (is 3)")]
+    fn test_cursor_mut_move_to_index_out_of_bounds() {
+        let mut list = DoublyLinkedList::from([1, 2, 3]);
+        list.cursor_front_mut().move_to_index(4);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let list: DoublyLinkedList<i32> = "1,2,3".parse().unwrap();
+        assert_eq!(list, vec![1, 2, 3]);
+        let list: DoublyLinkedList<i32> = "  4 ,5,\t6  ".parse().unwrap();
+        assert_eq!(list, vec![4, 5, 6]);
+        let list: DoublyLinkedList<i32> = "".parse().unwrap();
+        assert!(list.is_empty());
+
+        let err = "1,x,3".parse::<DoublyLinkedList<i32>>().unwrap_err();
+        assert_eq!(err, "x".parse::<i32>().unwrap_err());
+        assert!("1,,3".parse::<DoublyLinkedList<i32>>().is_err());
     }
 }